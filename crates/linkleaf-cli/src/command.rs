@@ -1,12 +1,25 @@
-use anyhow::{Result, bail};
-use linkleaf_core::fs::write_feed;
+use anyhow::{Context, Result, bail};
+use linkleaf_core::check::{self, CheckOptions, LinkStatus};
+use linkleaf_core::fs::{read_feed, write_feed};
+use linkleaf_core::html::copy_static_assets;
 use linkleaf_core::linkleaf_proto::Feed;
-use linkleaf_core::{add, list};
+use linkleaf_core::import;
+use linkleaf_core::pull;
+use linkleaf_core::{
+    IdStrategy, add, etag_for, feed_to_json_feed, feed_to_rss_xml, list, render_feed_html,
+};
 use std::path::PathBuf;
+use std::process::Command;
+use std::time::Duration;
 use time::Date;
 use uuid::Uuid;
 
-pub fn cmd_init(file: PathBuf, title: String, version: u32) -> Result<()> {
+pub fn cmd_init(
+    file: PathBuf,
+    title: String,
+    version: u32,
+    hook: Option<String>,
+) -> Result<()> {
     if file.exists() {
         bail!("file already exists: {}", file.display());
     }
@@ -22,6 +35,9 @@ pub fn cmd_init(file: PathBuf, title: String, version: u32) -> Result<()> {
         modified_feed.version,
         file.display()
     );
+    if let Some(hook) = hook {
+        linkleaf_core::fs::run_hook(&hook, &file, "init", &[])?;
+    }
     Ok(())
 }
 
@@ -33,8 +49,13 @@ pub fn cmd_add(
     tags: Option<String>,
     via: Option<String>,
     id: Option<Uuid>,
+    id_strategy: IdStrategy,
+    hook: Option<String>,
 ) -> Result<()> {
-    add(file, title, url, summary, tags, via, id)?;
+    let link = add(file.clone(), title, url, summary, tags, via, id, id_strategy)?;
+    if let Some(hook) = hook {
+        linkleaf_core::fs::run_hook(&hook, &file, "add", &[link.id])?;
+    }
     Ok(())
 }
 
@@ -43,8 +64,10 @@ pub fn cmd_list(
     long: bool,
     tags: Option<Vec<String>>,
     date: Option<Date>,
+    since: Option<Date>,
+    until: Option<Date>,
 ) -> Result<()> {
-    let feed = list(&file, tags, date)?;
+    let feed = list(&file, tags, date, since, until)?;
 
     if long {
         long_print(feed);
@@ -75,6 +98,354 @@ pub fn cmd_list(
     Ok(())
 }
 
+/// Render the feed to HTML and write it to `out`.
+///
+/// When `templates` is given, `feed.html` (and a `static/` assets directory,
+/// if present) are loaded from that directory instead of the built-in
+/// template, and `static/` is copied next to `out` so the page can reference
+/// custom CSS/JS.
+pub fn cmd_html(
+    file: PathBuf,
+    out: PathBuf,
+    title: Option<String>,
+    templates: Option<PathBuf>,
+    limit: Option<usize>,
+    etag: bool,
+) -> Result<()> {
+    let feed = read_feed(&file)?;
+    let html = render_feed_html(&feed, title, templates.as_deref(), limit)?;
+
+    if let Some(parent) = out.parent() {
+        if !parent.as_os_str().is_empty() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("failed to create directory: {}", parent.display()))?;
+        }
+    }
+    std::fs::write(&out, html.as_bytes())
+        .with_context(|| format!("failed to write: {}", out.display()))?;
+
+    if etag {
+        write_etag_sidecar(&out, html.as_bytes())?;
+    }
+
+    if let Some(dest_dir) = out.parent() {
+        copy_static_assets(templates.as_deref(), dest_dir)?;
+    }
+
+    eprintln!("Wrote HTML: {}", out.display());
+    Ok(())
+}
+
+/// Generate an RSS 2.0 feed for `feed_file` and print it to stdout, or write
+/// it to `out` when given.
+pub fn cmd_gen_rss(
+    feed_file: PathBuf,
+    site_title: &str,
+    site_link: &str,
+    limit: Option<usize>,
+    out: Option<PathBuf>,
+    etag: bool,
+) -> Result<()> {
+    let feed = read_feed(&feed_file)?;
+    let rss_feed = feed_to_rss_xml(&feed, site_title, site_link, limit)?;
+
+    match &out {
+        Some(out) => {
+            std::fs::write(out, rss_feed.as_bytes())
+                .with_context(|| format!("failed to write: {}", out.display()))?;
+            if etag {
+                write_etag_sidecar(out, rss_feed.as_bytes())?;
+            }
+            eprintln!("Wrote RSS: {}", out.display());
+        }
+        None => println!("{}", rss_feed),
+    }
+
+    Ok(())
+}
+
+/// Write a `<path>.etag` sidecar (e.g. `index.html.etag`) containing a
+/// strong ETag derived from `bytes`, so a static host or reverse proxy can
+/// serve conditional `If-None-Match`/304 responses.
+fn write_etag_sidecar(path: &std::path::Path, bytes: &[u8]) -> Result<()> {
+    let mut etag_path = path.as_os_str().to_os_string();
+    etag_path.push(".etag");
+    let etag_path = PathBuf::from(etag_path);
+    std::fs::write(&etag_path, etag_for(bytes))
+        .with_context(|| format!("failed to write: {}", etag_path.display()))?;
+    Ok(())
+}
+
+/// Commit (and, by default, push) the feed file to a git remote, then run an
+/// optional post-publish hook.
+pub fn cmd_publish(
+    file: PathBuf,
+    remote: &str,
+    branch: Option<String>,
+    message: &str,
+    allow_empty: bool,
+    no_push: bool,
+    hook: Option<String>,
+) -> Result<()> {
+    let feed = read_feed(&file)?;
+
+    run_git(&["add", "--", &file.display().to_string()])?;
+
+    let mut commit_args = vec!["commit", "-m", message];
+    if allow_empty {
+        commit_args.push("--allow-empty");
+    }
+    run_git(&commit_args)?;
+
+    if !no_push {
+        let mut push_args = vec!["push".to_string(), remote.to_string()];
+        if let Some(b) = &branch {
+            push_args.push(b.clone());
+        }
+        run_git(&push_args.iter().map(String::as_str).collect::<Vec<_>>())?;
+    }
+
+    if let Some(hook) = hook {
+        run_hook(&hook, &file, remote, branch.as_deref(), feed.links.len())?;
+    }
+
+    eprintln!("Published: {}", file.display());
+    Ok(())
+}
+
+fn run_git(args: &[&str]) -> Result<()> {
+    let status = Command::new("git")
+        .args(args)
+        .status()
+        .with_context(|| format!("failed to run: git {}", args.join(" ")))?;
+    if !status.success() {
+        bail!("git {} failed: {status}", args.join(" "));
+    }
+    Ok(())
+}
+
+fn run_hook(
+    hook: &str,
+    file: &PathBuf,
+    remote: &str,
+    branch: Option<&str>,
+    link_count: usize,
+) -> Result<()> {
+    let status = Command::new("sh")
+        .arg("-c")
+        .arg(hook)
+        .env("LINKLEAF_FEED_PATH", file)
+        .env("LINKLEAF_REMOTE", remote)
+        .env("LINKLEAF_BRANCH", branch.unwrap_or(""))
+        .env("LINKLEAF_LINK_COUNT", link_count.to_string())
+        .status()
+        .with_context(|| format!("failed to run hook: {hook}"))?;
+
+    if !status.success() {
+        bail!("publish hook exited with {status}: {hook}");
+    }
+    Ok(())
+}
+
+pub fn cmd_pull(file: PathBuf, urls: Vec<String>, subscribe: bool) -> Result<()> {
+    if subscribe {
+        for url in &urls {
+            pull::subscribe(url)?;
+        }
+    }
+
+    let urls = if urls.is_empty() {
+        pull::tracked_feeds()?
+    } else {
+        urls
+    };
+
+    if urls.is_empty() {
+        eprintln!("no feed URLs given and no subscriptions tracked; nothing to pull");
+        return Ok(());
+    }
+
+    let stats = pull::pull(&file, &urls)?;
+    eprintln!(
+        "Pulled {} feed(s): {} inserted, {} updated",
+        urls.len(),
+        stats.inserted,
+        stats.updated
+    );
+    Ok(())
+}
+
+/// List the named feeds currently under the XDG data directory.
+pub fn cmd_feeds() -> Result<()> {
+    let feeds = linkleaf_core::xdg::list_feeds()?;
+    if feeds.is_empty() {
+        eprintln!("no named feeds yet; `init`/`add` without a FEED argument creates the 'default' one");
+        return Ok(());
+    }
+    for name in feeds {
+        println!("{name}");
+    }
+    Ok(())
+}
+
+/// Probe every link in the feed and print a one-line reachability report per
+/// distinct URL.
+pub fn cmd_check(file: PathBuf, workers: usize, timeout_secs: u64, follow_redirects: bool) -> Result<()> {
+    let feed = read_feed(&file)?;
+    let opts = CheckOptions {
+        workers,
+        timeout: Duration::from_secs(timeout_secs),
+        follow_redirects,
+    };
+    let results = check::check(&feed, &opts);
+
+    let mut broken = 0;
+    for status in &results {
+        match status {
+            LinkStatus::Ok { url } => println!("OK        {url}"),
+            LinkStatus::Redirected { url, final_url } => {
+                println!("REDIRECT  {url} -> {final_url}")
+            }
+            LinkStatus::ClientError { url, status } => {
+                broken += 1;
+                println!("CLIENT {status}  {url}");
+            }
+            LinkStatus::ServerError { url, status } => {
+                broken += 1;
+                println!("SERVER {status}  {url}");
+            }
+            LinkStatus::Timeout { url } => {
+                broken += 1;
+                println!("TIMEOUT   {url}");
+            }
+            LinkStatus::UnresolvedDns { url } => {
+                broken += 1;
+                println!("DNS       {url}");
+            }
+        }
+    }
+    eprintln!("Checked {} link(s): {} broken", results.len(), broken);
+    Ok(())
+}
+
+/// Import one or more remote RSS/Atom/JSON Feed documents, preserving each
+/// entry's own published/updated date. Unlike `pull`, this never touches
+/// the persisted subscription list — it's a one-off import of exactly the
+/// URLs given.
+pub fn cmd_import(file: PathBuf, urls: Vec<String>) -> Result<()> {
+    let stats = import::import(&file, &urls)?;
+    eprintln!(
+        "Imported {} feed(s): {} inserted, {} updated",
+        urls.len(),
+        stats.inserted,
+        stats.updated
+    );
+    Ok(())
+}
+
+/// Serve the feed over HTTP: `/` renders HTML, `/feed.xml` is the RSS 2.0
+/// export, and `/feed.json` is the JSON Feed export. The feed is re-read
+/// from `file` on every request, so edits made via `add` (or any other
+/// command) show up on the next reload.
+pub fn cmd_serve(
+    file: PathBuf,
+    addr: &str,
+    title: Option<String>,
+    link: Option<String>,
+) -> Result<()> {
+    let site_link = link.unwrap_or_else(|| format!("http://{addr}/"));
+
+    let server = tiny_http::Server::http(addr)
+        .map_err(|e| anyhow::anyhow!("failed to bind {addr}: {e}"))?;
+    eprintln!("Serving '{}' on http://{addr}/", file.display());
+
+    for request in server.incoming_requests() {
+        let result = handle_request(&request, &file, title.clone(), &site_link);
+        let response = match result {
+            Ok(response) => response,
+            Err(err) => tiny_http::Response::from_string(format!("internal error: {err}"))
+                .with_status_code(500)
+                .boxed(),
+        };
+        let _ = request.respond(response);
+    }
+
+    Ok(())
+}
+
+fn handle_request(
+    request: &tiny_http::Request,
+    file: &PathBuf,
+    title: Option<String>,
+    site_link: &str,
+) -> Result<tiny_http::ResponseBox> {
+    let feed = read_feed(file)?;
+    let site_title = title.clone().unwrap_or_else(|| feed.title.clone());
+
+    let (content_type, body): (&str, String) = match request.url() {
+        "/" => (
+            "text/html; charset=utf-8",
+            render_feed_html(&feed, title, None, None)?,
+        ),
+        "/feed.xml" => (
+            "application/rss+xml; charset=utf-8",
+            feed_to_rss_xml(&feed, &site_title, site_link, None)?,
+        ),
+        "/feed.json" => (
+            "application/feed+json",
+            feed_to_json_feed(&feed, &site_title, site_link, None)?,
+        ),
+        _ => {
+            return Ok(tiny_http::Response::from_string("not found")
+                .with_status_code(404)
+                .boxed());
+        }
+    };
+
+    let etag = etag_for(body.as_bytes());
+    let not_modified = request
+        .headers()
+        .iter()
+        .any(|h| h.field.equiv("If-None-Match") && h.value.as_str() == etag);
+
+    if not_modified {
+        return Ok(tiny_http::Response::empty(304)
+            .with_header(etag_header(&etag))
+            .boxed());
+    }
+
+    Ok(tiny_http::Response::from_string(body)
+        .with_header(content_type_header(content_type))
+        .with_header(cache_control_header())
+        .with_header(etag_header(&etag))
+        .boxed())
+}
+
+fn content_type_header(value: &str) -> tiny_http::Header {
+    tiny_http::Header::from_bytes(&b"Content-Type"[..], value.as_bytes()).unwrap()
+}
+
+fn cache_control_header() -> tiny_http::Header {
+    tiny_http::Header::from_bytes(&b"Cache-Control"[..], &b"no-cache, must-revalidate"[..])
+        .unwrap()
+}
+
+fn etag_header(etag: &str) -> tiny_http::Header {
+    tiny_http::Header::from_bytes(&b"ETag"[..], etag.as_bytes()).unwrap()
+}
+
+pub fn cmd_json(
+    feed_file: PathBuf,
+    site_title: &str,
+    site_link: &str,
+    limit: Option<usize>,
+) -> Result<()> {
+    let feed = read_feed(&feed_file)?;
+    let json_feed = feed_to_json_feed(&feed, site_title, site_link, limit)?;
+    println!("{}", json_feed);
+    Ok(())
+}
+
 fn long_print(feed: Feed) {
     println!("Feed: '{}' (v{})\n", feed.title, feed.version);
     for l in &feed.links {
@@ -110,7 +481,7 @@ mod tests {
             id: "one".into(),
             title: "First".into(),
             url: "https://example.com/1".into(),
-            date: "2025-01-01".into(),
+            date: "2025-01-01 10:00:00".into(),
             summary: "hello".into(),
             tags: vec!["x".into(), "y".into()],
             via: "".into(),
@@ -122,7 +493,7 @@ mod tests {
     fn init_creates_file_and_defaults() -> anyhow::Result<()> {
         let tmp = TempDir::new()?;
         let path = tmp.path().join("nested/dir/mylinks.pb");
-        cmd_init(path.clone(), "My Links".into(), 2)?;
+        cmd_init(path.clone(), "My Links".into(), 2, None)?;
         assert!(path.exists(), "feed file should exist");
         let feed = read_feed(&PathBuf::from(&path))?;
         assert_eq!(feed.title, "My Links");
@@ -147,6 +518,8 @@ mod tests {
             Some("rust,book".into()),
             Some("https://rust-lang.org".into()),
             Some(_id.clone()), // ensure deterministic update target
+            IdStrategy::Uuid,
+            None,
         )?;
         let mut feed = read_feed(&PathBuf::from(&path))?;
         assert_eq!(feed.links.len(), 1);
@@ -162,6 +535,8 @@ mod tests {
             Some("rust,book".into()),
             None,
             Some(_id.into()),
+            IdStrategy::Uuid,
+            None,
         )?;
         feed = read_feed(&PathBuf::from(&path))?;
         assert_eq!(feed.links.len(), 1, "should update, not duplicate");
@@ -177,8 +552,31 @@ mod tests {
         write_feed(&PathBuf::from(&path), sample_feed_one())?;
 
         // We don’t assert output formatting here; just ensure no panic/err.
-        cmd_list(path.clone(), false, None, None)?;
-        cmd_list(path.clone(), true, None, None)?;
+        cmd_list(path.clone(), false, None, None, None, None)?;
+        cmd_list(path.clone(), true, None, None, None, None)?;
+        Ok(())
+    }
+
+    #[test]
+    fn list_accepts_since_and_until_filters() -> anyhow::Result<()> {
+        let tmp = TempDir::new()?;
+        let path = tmp.path().join("feed.pb");
+        write_feed(&PathBuf::from(&path), sample_feed_one())?;
+
+        let jan1 = time::macros::date!(2025 - 01 - 01);
+        let jan2 = time::macros::date!(2025 - 01 - 02);
+
+        // Single-day match via --date.
+        let feed = list(&path, None, Some(jan1), None, None)?;
+        assert_eq!(feed.links.len(), 1, "sample link falls on jan1");
+
+        // Empty result: no link falls on this day.
+        let feed = list(&path, None, Some(jan2), None, None)?;
+        assert_eq!(feed.links.len(), 0, "no link falls on jan2");
+
+        // Range boundaries: [jan1, jan2) includes the one sample link.
+        let feed = list(&path, None, None, Some(jan1), Some(jan2))?;
+        assert_eq!(feed.links.len(), 1, "sample link falls within [jan1, jan2)");
         Ok(())
     }
 }
@@ -2,7 +2,10 @@ mod command;
 
 use anyhow::Result;
 use clap::{Args, Parser, Subcommand};
-use command::{cmd_add, cmd_html, cmd_init, cmd_list, cmd_publish};
+use command::{
+    cmd_add, cmd_check, cmd_feeds, cmd_gen_rss, cmd_html, cmd_import, cmd_init, cmd_json,
+    cmd_list, cmd_publish, cmd_pull, cmd_serve,
+};
 use linkleaf_core::validation::{parse_date, parse_tags};
 use std::path::PathBuf;
 use time::Date;
@@ -31,13 +34,115 @@ enum Commands {
 
     /// Commit & push the feed file to a git remote
     Publish(PublishArgs),
+
+    /// Fetch remote RSS/Atom/JSON feeds and merge their entries into the feed
+    Pull(PullArgs),
+
+    /// Import remote RSS/Atom/JSON feeds, preserving each entry's own date
+    Import(ImportArgs),
+
+    /// Probe every link's URL and report which ones are dead or redirected
+    Check(CheckArgs),
+
+    /// Export the feed as a JSON Feed (version 1.1) document
+    Json(JsonArgs),
+
+    /// Generate an RSS 2.0 feed
+    Rss(RssArgs),
+
+    /// Serve the feed as HTML, RSS, and JSON Feed over HTTP
+    Serve(ServeArgs),
+
+    /// List the named feeds under the XDG data directory
+    Feeds,
+}
+
+#[derive(Args)]
+struct ServeArgs {
+    /// Path to the feed .pb file, or a named feed (defaults to the XDG default feed)
+    #[arg(value_name = "FEED")]
+    file: Option<String>,
+
+    /// Address to listen on
+    #[arg(short, long, default_value = "127.0.0.1:8080")]
+    addr: String,
+
+    /// Page/channel title (defaults to feed.title)
+    #[arg(short, long)]
+    title: Option<String>,
+
+    /// Site link used as the RSS channel link (defaults to http://<addr>/)
+    #[arg(short, long)]
+    link: Option<String>,
+}
+
+#[derive(Args)]
+struct JsonArgs {
+    /// Input feed .pb file, or a named feed (defaults to the XDG default feed)
+    #[arg(value_name = "FEED")]
+    file: Option<String>,
+
+    /// Site Title
+    #[arg(short = 't', long = "title", default_value = "My Links")]
+    site_title: String,
+
+    /// Site Link
+    #[arg(short = 'l', long = "link", default_value = "https://www.example.com")]
+    site_link: String,
+
+    /// Only emit the newest N items
+    #[arg(long)]
+    limit: Option<usize>,
+}
+
+#[derive(Args)]
+struct PullArgs {
+    /// Path to the feed .pb file, or a named feed (defaults to the XDG default feed)
+    #[arg(value_name = "FEED")]
+    file: Option<String>,
+
+    /// One or more feed URLs to pull; if omitted, refreshes every tracked subscription
+    urls: Vec<String>,
+
+    /// Remember these URLs so a bare `pull` refreshes them later
+    #[arg(long)]
+    subscribe: bool,
+}
+
+#[derive(Args)]
+struct ImportArgs {
+    /// Path to the feed .pb file, or a named feed (defaults to the XDG default feed)
+    #[arg(value_name = "FEED")]
+    file: Option<String>,
+
+    /// One or more feed URLs to import
+    urls: Vec<String>,
+}
+
+#[derive(Args)]
+struct CheckArgs {
+    /// Path to the feed .pb file, or a named feed (defaults to the XDG default feed)
+    #[arg(value_name = "FEED")]
+    file: Option<String>,
+
+    /// Number of links probed concurrently
+    #[arg(short, long, default_value_t = 4)]
+    workers: usize,
+
+    /// Per-request timeout, in seconds
+    #[arg(long, default_value_t = 10)]
+    timeout: u64,
+
+    /// Report redirects as-is instead of following them
+    #[arg(long)]
+    no_follow_redirects: bool,
 }
 
 #[derive(Args)]
 struct PublishArgs {
-    /// Path to the feed .pb file
-    #[arg(value_name = "FILE", default_value = "feed/mylinks.pb")]
-    file: PathBuf,
+    /// Path to the feed .pb file, or a named feed (defaults to the XDG default feed)
+    #[arg(value_name = "FEED")]
+    file: Option<String>,
 
     /// Git remote name
     #[arg(short, long, default_value = "origin")]
@@ -58,13 +163,17 @@ struct PublishArgs {
     /// Do not push (only commit)
     #[arg(long)]
     no_push: bool,
+
+    /// Command to run after a successful commit/push (e.g. to regenerate HTML)
+    #[arg(long, env = "LINKLEAF_HOOK")]
+    hook: Option<String>,
 }
 
 #[derive(Args)]
 struct ListArgs {
-    /// Path to the feed .pb file
-    #[arg(value_name = "FILE", default_value = "feed/mylinks.pb")]
-    file: PathBuf,
+    /// Path to the feed .pb file, or a named feed (defaults to the XDG default feed)
+    #[arg(value_name = "FEED")]
+    file: Option<String>,
 
     /// Show detailed, multi-line output
     #[arg(short = 'l', long = "long", alias = "detail")]
@@ -77,13 +186,21 @@ struct ListArgs {
     /// Filter by Date (YYYY-MM-DD)
     #[arg(short, long, value_name = "YYYY-MM-DD", value_parser = parse_date)]
     date: Option<Date>,
+
+    /// Only show links on or after this date (YYYY-MM-DD)
+    #[arg(long, value_name = "YYYY-MM-DD", value_parser = parse_date)]
+    since: Option<Date>,
+
+    /// Only show links strictly before this date (YYYY-MM-DD)
+    #[arg(long, value_name = "YYYY-MM-DD", value_parser = parse_date)]
+    until: Option<Date>,
 }
 
 #[derive(Args)]
 struct InitArgs {
-    /// Path to create the feed .pb file
-    #[arg(value_name = "FILE", default_value = "feed/mylinks.pb")]
-    file: PathBuf,
+    /// Path to create the feed .pb file, or a named feed (defaults to the XDG default feed)
+    #[arg(value_name = "FEED")]
+    file: Option<String>,
 
     /// Feed title
     #[arg(short, long, default_value = "My Links")]
@@ -92,13 +209,17 @@ struct InitArgs {
     /// Feed version
     #[arg(short, long, default_value = "1")]
     version: u32,
+
+    /// Command to run after the feed is created
+    #[arg(long, env = "LINKLEAF_HOOK")]
+    hook: Option<String>,
 }
 
 #[derive(Args)]
 struct AddArgs {
-    /// Path to the feed .pb file
-    #[arg(value_name = "FILE", default_value = "feed/mylinks.pb")]
-    file: PathBuf,
+    /// Path to the feed .pb file, or a named feed (defaults to the XDG default feed)
+    #[arg(value_name = "FEED")]
+    file: Option<String>,
 
     /// Link title
     #[arg(short, long)]
@@ -123,13 +244,40 @@ struct AddArgs {
     /// Override auto id (defaults to sha256(url|date)[:12])
     #[arg(long)]
     id: Option<Uuid>,
+
+    /// Strategy used to generate an id for a new link when `--id` is not given
+    #[arg(long, value_enum, default_value_t = IdStrategyArg::ContentHash)]
+    id_strategy: IdStrategyArg,
+
+    /// Command to run after the link is added, fed its id on stdin
+    #[arg(long, env = "LINKLEAF_HOOK")]
+    hook: Option<String>,
+}
+
+/// CLI-facing mirror of [`linkleaf_core::IdStrategy`] (kept separate so
+/// `linkleaf_core` doesn't need to depend on `clap`).
+#[derive(Clone, Copy, clap::ValueEnum)]
+enum IdStrategyArg {
+    Uuid,
+    ContentHash,
+    UuidV5,
+}
+
+impl From<IdStrategyArg> for linkleaf_core::IdStrategy {
+    fn from(arg: IdStrategyArg) -> Self {
+        match arg {
+            IdStrategyArg::Uuid => linkleaf_core::IdStrategy::Uuid,
+            IdStrategyArg::ContentHash => linkleaf_core::IdStrategy::ContentHash,
+            IdStrategyArg::UuidV5 => linkleaf_core::IdStrategy::UuidV5,
+        }
+    }
 }
 
 #[derive(Args)]
 struct HtmlArgs {
-    /// Input feed .pb file
-    #[arg(value_name = "FILE", default_value = "feed/mylinks.pb")]
-    file: PathBuf,
+    /// Input feed .pb file, or a named feed (defaults to the XDG default feed)
+    #[arg(value_name = "FEED")]
+    file: Option<String>,
 
     /// Output HTML file (e.g., docs/index.html)
     #[arg(short, long, default_value = "assets/index.html")]
@@ -138,6 +286,46 @@ struct HtmlArgs {
     /// Page title (defaults to feed.title)
     #[arg(short, long)]
     title: Option<String>,
+
+    /// Directory holding a custom `feed.html` (and optional `static/` assets)
+    /// to use instead of the built-in template
+    #[arg(long)]
+    templates: Option<PathBuf>,
+
+    /// Only render the newest N links
+    #[arg(long)]
+    limit: Option<usize>,
+
+    /// Also write a `<out>.etag` sidecar with a strong ETag of the rendered bytes
+    #[arg(long)]
+    etag: bool,
+}
+
+#[derive(Args)]
+struct RssArgs {
+    /// Path to the feed .pb file, or a named feed (defaults to the XDG default feed)
+    #[arg(value_name = "FEED")]
+    file: Option<String>,
+
+    /// Site Title
+    #[arg(short = 't', long = "title", default_value = "My Links")]
+    site_title: String,
+
+    /// Site Link
+    #[arg(short = 'l', long = "link", default_value = "https://www.example.com")]
+    site_link: String,
+
+    /// Only emit the newest N items
+    #[arg(long)]
+    limit: Option<usize>,
+
+    /// Write the feed to this file instead of stdout
+    #[arg(short, long)]
+    out: Option<PathBuf>,
+
+    /// Also write a `<out>.etag` sidecar with a strong ETag of the rendered bytes (requires --out)
+    #[arg(long)]
+    etag: bool,
 }
 
 fn main() -> Result<()> {
@@ -151,25 +339,83 @@ fn main() -> Result<()> {
     }
     let cli = Cli::parse();
     match cli.command {
-        Commands::Init(args) => cmd_init(args.file, args.title, args.version),
+        Commands::Init(args) => cmd_init(
+            linkleaf_core::xdg::resolve_feed(args.file.as_deref())?,
+            args.title,
+            args.version,
+            args.hook,
+        ),
         Commands::Add(args) => cmd_add(
-            args.file,
+            linkleaf_core::xdg::resolve_feed(args.file.as_deref())?,
             args.title,
             args.url,
             args.summary,
             args.tags,
             args.via,
             args.id,
+            args.id_strategy.into(),
+            args.hook,
+        ),
+        Commands::List(args) => cmd_list(
+            linkleaf_core::xdg::resolve_feed(args.file.as_deref())?,
+            args.long,
+            args.tags,
+            args.date,
+            args.since,
+            args.until,
+        ),
+        Commands::Html(args) => cmd_html(
+            linkleaf_core::xdg::resolve_feed(args.file.as_deref())?,
+            args.out,
+            args.title,
+            args.templates,
+            args.limit,
+            args.etag,
         ),
-        Commands::List(args) => cmd_list(args.file, args.long, args.tags, args.date),
-        Commands::Html(args) => cmd_html(args.file, args.out, args.title),
         Commands::Publish(args) => cmd_publish(
-            args.file,
+            linkleaf_core::xdg::resolve_feed(args.file.as_deref())?,
             &args.remote,
             args.branch,
             &args.message,
             args.allow_empty,
             args.no_push,
+            args.hook,
+        ),
+        Commands::Pull(args) => cmd_pull(
+            linkleaf_core::xdg::resolve_feed(args.file.as_deref())?,
+            args.urls,
+            args.subscribe,
+        ),
+        Commands::Import(args) => cmd_import(
+            linkleaf_core::xdg::resolve_feed(args.file.as_deref())?,
+            args.urls,
+        ),
+        Commands::Check(args) => cmd_check(
+            linkleaf_core::xdg::resolve_feed(args.file.as_deref())?,
+            args.workers,
+            args.timeout,
+            !args.no_follow_redirects,
+        ),
+        Commands::Json(args) => cmd_json(
+            linkleaf_core::xdg::resolve_feed(args.file.as_deref())?,
+            &args.site_title,
+            &args.site_link,
+            args.limit,
+        ),
+        Commands::Rss(args) => cmd_gen_rss(
+            linkleaf_core::xdg::resolve_feed(args.file.as_deref())?,
+            &args.site_title,
+            &args.site_link,
+            args.limit,
+            args.out,
+            args.etag,
+        ),
+        Commands::Serve(args) => cmd_serve(
+            linkleaf_core::xdg::resolve_feed(args.file.as_deref())?,
+            &args.addr,
+            args.title,
+            args.link,
         ),
+        Commands::Feeds => cmd_feeds(),
     }
 }
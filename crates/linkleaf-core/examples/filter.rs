@@ -1,7 +1,7 @@
 use anyhow::Result;
 use tempfile::tempdir;
 
-use linkleaf_core::{add, list};
+use linkleaf_core::{IdStrategy, add, list};
 use time::{OffsetDateTime, UtcOffset};
 
 fn main() -> Result<()> {
@@ -17,6 +17,7 @@ fn main() -> Result<()> {
         Some("rust, async".into()),
         None,
         None,
+        IdStrategy::Uuid,
     )?;
     let _ = add(
         file.clone(),
@@ -26,6 +27,7 @@ fn main() -> Result<()> {
         Some("tokio".into()),
         None,
         None,
+        IdStrategy::Uuid,
     )?;
     let _ = add(
         file.clone(),
@@ -35,10 +37,11 @@ fn main() -> Result<()> {
         Some("db, rust".into()),
         None,
         None,
+        IdStrategy::Uuid,
     )?;
 
     // Filter by tag (case-insensitive, any-of)
-    let rust_only = list(&file, Some(vec!["RUST".into()]), None)?;
+    let rust_only = list(&file, Some(vec!["RUST".into()]), None, None, None)?;
     println!("rust_only: {}", rust_only.links.len());
     for l in &rust_only.links {
         println!("- {}", l.title);
@@ -48,7 +51,7 @@ fn main() -> Result<()> {
     let today = OffsetDateTime::now_utc()
         .to_offset(UtcOffset::current_local_offset()?)
         .date();
-    let today_only = list(&file, None, Some(today))?;
+    let today_only = list(&file, None, Some(today), None, None)?;
     println!("today_only: {}", today_only.links.len());
 
     Ok(())
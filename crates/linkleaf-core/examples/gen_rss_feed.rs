@@ -1,7 +1,7 @@
 use anyhow::Result;
 use tempfile::tempdir;
 
-use linkleaf_core::{add, feed_to_rss_xml, list};
+use linkleaf_core::{IdStrategy, add, feed_to_rss_xml, list};
 
 fn main() -> Result<()> {
     let dir = tempdir()?;
@@ -15,11 +15,12 @@ fn main() -> Result<()> {
         Some("rust, async, tokio".into()),
         Some("website".into()),
         None, // generate id
+        IdStrategy::Uuid,
     )?;
 
-    let feed = list(&file, None, None)?;
+    let feed = list(&file, None, None, None, None)?;
 
-    let rss_feed = feed_to_rss_xml(&feed, "", "")?;
+    let rss_feed = feed_to_rss_xml(&feed, "", "", None)?;
 
     println!("{}", rss_feed);
 
@@ -0,0 +1,16 @@
+use anyhow::Result;
+use linkleaf_core::{list, pull};
+use tempfile::tempdir;
+
+fn main() -> Result<()> {
+    let dir = tempdir()?;
+    let file = dir.path().join("feed.pb");
+
+    let stats = pull::pull(&file, &["https://example.com/feed.xml".to_string()])?;
+    println!("inserted: {}, updated: {}", stats.inserted, stats.updated);
+
+    let feed = list(&file, None, None, None, None)?;
+    println!("links: {}", feed.links.len());
+
+    Ok(())
+}
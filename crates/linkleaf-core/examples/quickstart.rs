@@ -1,7 +1,7 @@
 use anyhow::Result;
 use tempfile::tempdir;
 
-use linkleaf_core::{add, list};
+use linkleaf_core::{IdStrategy, add, list};
 use time::{OffsetDateTime, UtcOffset};
 
 fn main() -> Result<()> {
@@ -16,10 +16,11 @@ fn main() -> Result<()> {
         Some("rust, async, tokio".into()),
         Some("website".into()),
         None, // generate id
+        IdStrategy::Uuid,
     )?;
 
     // list everything
-    let feed = list(&file, None, None)?;
+    let feed = list(&file, None, None, None, None)?;
     println!("feed version: {}", feed.version);
     println!("links: {}", feed.links.len());
     for (i, l) in feed.links.iter().enumerate() {
@@ -2,7 +2,7 @@ use anyhow::Result;
 use tempfile::tempdir;
 use uuid::Uuid;
 
-use linkleaf_core::{add, list};
+use linkleaf_core::{IdStrategy, add, list};
 
 fn main() -> Result<()> {
     let dir = tempdir()?;
@@ -16,6 +16,7 @@ fn main() -> Result<()> {
         Some("alpha".into()),
         None,
         None,
+        IdStrategy::Uuid,
     )?;
 
     // Update the same logical item by id
@@ -27,11 +28,12 @@ fn main() -> Result<()> {
         Some("rust,updated".into()),
         Some("hn".into()),
         Some(Uuid::parse_str(&first.id)?),
+        IdStrategy::Uuid,
     )?;
 
     assert_eq!(updated.id, first.id, "id stays the same on upsert");
 
-    let feed = list(&file, None, None)?;
+    let feed = list(&file, None, None, None, None)?;
     println!("links: {}", feed.links.len());
     println!("front item: {} [{}]", feed.links[0].title, feed.links[0].id);
 
@@ -1,7 +1,7 @@
 use anyhow::Result;
 use tempfile::tempdir;
 
-use linkleaf_core::{add, list};
+use linkleaf_core::{IdStrategy, add, list};
 
 fn main() -> Result<()> {
     let dir = tempdir()?;
@@ -15,6 +15,7 @@ fn main() -> Result<()> {
         Some("t1".into()),
         None,
         None,
+        IdStrategy::Uuid,
     )?;
 
     // Same URL + id=None -> update the existing entry (moved to front)
@@ -26,11 +27,12 @@ fn main() -> Result<()> {
         Some("t2".into()),
         None,
         None,
+        IdStrategy::Uuid,
     )?;
 
     assert_eq!(a.id, a2.id);
 
-    let feed = list(&file, None, None)?;
+    let feed = list(&file, None, None, None, None)?;
     println!(
         "front item: {} [{}] tags: {:?}",
         feed.links[0].title, feed.links[0].id, feed.links[0].tags
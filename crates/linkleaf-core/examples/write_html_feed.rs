@@ -0,0 +1,29 @@
+use anyhow::Result;
+use tempfile::tempdir;
+
+use linkleaf_core::{IdStrategy, add, list, render_feed_html};
+
+fn main() -> Result<()> {
+    let dir = tempdir()?;
+    let file = dir.path().join("feed.pb");
+
+    let _a = add(
+        file.clone(),
+        "Tokio - Asynchronous Rust",
+        "https://tokio.rs/".into(),
+        Some("A runtime for reliable async apps".into()),
+        Some("rust, async, tokio".into()),
+        Some("website".into()),
+        None, // generate id
+        IdStrategy::Uuid,
+    )?;
+
+    let feed = list(&file, None, None, None, None)?;
+
+    // No `templates_dir` given, so this renders with the built-in template.
+    let html = render_feed_html(&feed, None, None, None)?;
+
+    println!("{}", html);
+
+    Ok(())
+}
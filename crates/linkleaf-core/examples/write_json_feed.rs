@@ -0,0 +1,28 @@
+use anyhow::Result;
+use tempfile::tempdir;
+
+use linkleaf_core::{IdStrategy, add, feed_to_json_feed, list};
+
+fn main() -> Result<()> {
+    let dir = tempdir()?;
+    let file = dir.path().join("feed.pb");
+
+    let _a = add(
+        file.clone(),
+        "Tokio - Asynchronous Rust",
+        "https://tokio.rs/".into(),
+        Some("A runtime for reliable async apps".into()),
+        Some("rust, async, tokio".into()),
+        Some("website".into()),
+        None, // generate id
+        IdStrategy::Uuid,
+    )?;
+
+    let feed = list(&file, None, None, None, None)?;
+
+    let json_feed = feed_to_json_feed(&feed, "My Links", "https://www.example.com", None)?;
+
+    println!("{}", json_feed);
+
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use anyhow::Result;
 use tempfile::tempdir;
 
-use linkleaf_core::{add, list, rss};
+use linkleaf_core::{IdStrategy, add, list, rss};
 use time::{OffsetDateTime, UtcOffset};
 
 fn main() -> Result<()> {
@@ -16,13 +16,14 @@ fn main() -> Result<()> {
         Some("rust, async, tokio".into()),
         Some("website".into()),
         None, // generate id
+        IdStrategy::Uuid,
     )?;
 
-    let feed = list(&file, None, None)?;
+    let feed = list(&file, None, None, None, None)?;
 
-    //  let rss_feed = rss::feed_to_rss_xml(&feed, "", "");
+    let rss_feed = rss::feed_to_rss_xml(&feed, "", "", None)?;
 
-    //   println!(rss_feed);
+    println!("{}", rss_feed);
 
     Ok(())
 }
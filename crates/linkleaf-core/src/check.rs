@@ -0,0 +1,196 @@
+//! Dead-link checking: probe every URL in a [`Feed`] and report its
+//! reachability, so a curated feed can be kept from rotting as linked pages
+//! disappear or move.
+
+use crate::linkleaf_proto::Feed;
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex, mpsc};
+use std::thread;
+use std::time::Duration;
+
+/// Outcome of probing a single link's URL.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinkStatus {
+    /// Responded with a 2xx status.
+    Ok { url: String },
+    /// Responded after following one or more redirects; `final_url` is
+    /// where it landed, for callers that want to upsert the corrected URL
+    /// back into the feed via [`crate::add`]. When redirects aren't
+    /// followed, a 3xx response is reported here with `final_url` taken from
+    /// its `Location` header.
+    Redirected { url: String, final_url: String },
+    /// Responded with a 4xx status.
+    ClientError { url: String, status: u16 },
+    /// Responded with a 5xx status.
+    ServerError { url: String, status: u16 },
+    /// The request didn't complete within `CheckOptions::timeout`.
+    Timeout { url: String },
+    /// The host name didn't resolve.
+    UnresolvedDns { url: String },
+}
+
+impl LinkStatus {
+    /// The URL this status was reported for.
+    pub fn url(&self) -> &str {
+        match self {
+            LinkStatus::Ok { url }
+            | LinkStatus::Redirected { url, .. }
+            | LinkStatus::ClientError { url, .. }
+            | LinkStatus::ServerError { url, .. }
+            | LinkStatus::Timeout { url }
+            | LinkStatus::UnresolvedDns { url } => url,
+        }
+    }
+}
+
+/// Tuning knobs for [`check`].
+#[derive(Debug, Clone, Copy)]
+pub struct CheckOptions {
+    /// Number of URLs probed concurrently.
+    pub workers: usize,
+    /// Per-request timeout before a probe is reported as [`LinkStatus::Timeout`].
+    pub timeout: Duration,
+    /// Whether to follow redirects (and report the final URL) or report the
+    /// redirect itself.
+    pub follow_redirects: bool,
+}
+
+impl Default for CheckOptions {
+    fn default() -> Self {
+        Self {
+            workers: 4,
+            timeout: Duration::from_secs(10),
+            follow_redirects: true,
+        }
+    }
+}
+
+fn probe(url: &str, opts: &CheckOptions) -> LinkStatus {
+    let agent = ureq::AgentBuilder::new()
+        .timeout(opts.timeout)
+        .redirects(if opts.follow_redirects { 5 } else { 0 })
+        .build();
+
+    match agent.head(url).call() {
+        Ok(resp) if !opts.follow_redirects && (300..400).contains(&resp.status()) => {
+            LinkStatus::Redirected {
+                url: url.to_string(),
+                final_url: resp.header("location").unwrap_or_default().to_string(),
+            }
+        }
+        Ok(resp) => {
+            let final_url = resp.get_url().to_string();
+            if final_url != url {
+                LinkStatus::Redirected {
+                    url: url.to_string(),
+                    final_url,
+                }
+            } else {
+                LinkStatus::Ok {
+                    url: url.to_string(),
+                }
+            }
+        }
+        Err(ureq::Error::Status(code, _)) if code >= 500 => LinkStatus::ServerError {
+            url: url.to_string(),
+            status: code,
+        },
+        Err(ureq::Error::Status(code, _)) => LinkStatus::ClientError {
+            url: url.to_string(),
+            status: code,
+        },
+        Err(ureq::Error::Transport(t)) => {
+            if t.kind() == ureq::ErrorKind::Dns {
+                LinkStatus::UnresolvedDns {
+                    url: url.to_string(),
+                }
+            } else {
+                // ureq folds timeouts into a generic `Io` transport error;
+                // without a dedicated timeout kind, treat every other
+                // transport failure as a timeout rather than inventing a
+                // status the caller can't act on.
+                LinkStatus::Timeout {
+                    url: url.to_string(),
+                }
+            }
+        }
+    }
+}
+
+/// Collect each distinct URL in `feed`'s links, in first-seen order.
+fn dedup_urls(feed: &Feed) -> Vec<String> {
+    let mut seen = HashSet::new();
+    feed.links
+        .iter()
+        .map(|l| l.url.clone())
+        .filter(|u| seen.insert(u.clone()))
+        .collect()
+}
+
+/// Walk `feed`'s links and probe each distinct URL for reachability. Runs up
+/// to `opts.workers` probes concurrently; identical URLs are only checked
+/// once. Results are returned rather than printed, so both the CLI and
+/// library consumers can act on them (e.g. upserting a corrected URL back
+/// into the feed via [`crate::add`] on [`LinkStatus::Redirected`]).
+pub fn check(feed: &Feed, opts: &CheckOptions) -> Vec<LinkStatus> {
+    let urls = dedup_urls(feed);
+
+    let (tx, rx) = mpsc::channel();
+    let work = Arc::new(Mutex::new(urls.into_iter()));
+    let workers = opts.workers.max(1);
+    let mut handles = Vec::with_capacity(workers);
+    for _ in 0..workers {
+        let tx = tx.clone();
+        let work = Arc::clone(&work);
+        let opts = *opts;
+        handles.push(thread::spawn(move || {
+            loop {
+                let next = work.lock().unwrap().next();
+                let Some(url) = next else { break };
+                if tx.send(probe(&url, &opts)).is_err() {
+                    break;
+                }
+            }
+        }));
+    }
+    drop(tx);
+
+    let mut results: Vec<LinkStatus> = rx.into_iter().collect();
+    for handle in handles {
+        let _ = handle.join();
+    }
+    results.sort_by(|a, b| a.url().cmp(b.url()));
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linkleaf_proto::Link;
+
+    fn mk_link(url: &str) -> Link {
+        Link {
+            id: url.to_string(),
+            title: "t".into(),
+            url: url.to_string(),
+            date: "2025-01-01 00:00:00".into(),
+            summary: String::new(),
+            tags: vec![],
+            via: String::new(),
+        }
+    }
+
+    #[test]
+    fn dedup_urls_drops_repeats_but_keeps_order() {
+        let mut feed = Feed::default();
+        feed.links = vec![
+            mk_link("https://example.com/a"),
+            mk_link("https://example.com/b"),
+            mk_link("https://example.com/a"),
+        ];
+        assert_eq!(
+            dedup_urls(&feed),
+            vec!["https://example.com/a", "https://example.com/b"]
+        );
+    }
+}
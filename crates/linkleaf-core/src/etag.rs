@@ -0,0 +1,10 @@
+use sha2::{Digest, Sha256};
+
+/// Derive a strong HTTP `ETag` value from the final rendered bytes.
+///
+/// Callers should hash the bytes actually written/served, so the tag
+/// changes exactly when the content does.
+pub fn etag_for(bytes: &[u8]) -> String {
+    let digest = Sha256::digest(bytes);
+    format!("\"{digest:x}\"")
+}
@@ -0,0 +1,144 @@
+//! Export a [`Feed`] to standard feed formats for any feed reader, selectable
+//! via [`ExportFormat`]. RSS 2.0 delegates to [`crate::rss`]; Atom 1.0 is
+//! hand-rolled here since nothing else in the crate pulls in an Atom
+//! dependency.
+
+use crate::TS_FMT;
+use crate::linkleaf_proto::{Feed, Link};
+use anyhow::Result;
+use time::PrimitiveDateTime;
+use time::format_description::well_known::Rfc3339;
+
+/// Which standard feed format to serialize a [`Feed`] to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Rss,
+    Atom,
+}
+
+/// Serialize `feed` to `format`. `site_title`/`site_link` are channel-level
+/// fallbacks when `feed.title` is empty; `limit`, if given, caps output to
+/// the first `limit` (already newest-first) links.
+pub fn export_feed(
+    feed: &Feed,
+    format: ExportFormat,
+    site_title: &str,
+    site_link: &str,
+    limit: Option<usize>,
+) -> Result<String> {
+    match format {
+        ExportFormat::Rss => crate::rss::feed_to_rss_xml(feed, site_title, site_link, limit),
+        ExportFormat::Atom => feed_to_atom_xml(feed, site_title, site_link, limit),
+    }
+}
+
+fn parse_local(s: &str) -> Option<time::OffsetDateTime> {
+    let naive = PrimitiveDateTime::parse(s, TS_FMT).ok()?;
+    let local_off = time::OffsetDateTime::now_local().ok()?.offset();
+    Some(naive.assume_offset(local_off))
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn link_to_atom_entry(l: &Link) -> String {
+    let updated = parse_local(&l.date)
+        .and_then(|dt| dt.format(&Rfc3339).ok())
+        .unwrap_or_default();
+    let summary = (!l.summary.is_empty())
+        .then(|| format!("  <summary>{}</summary>\n", escape(&l.summary)))
+        .unwrap_or_default();
+    let author = (!l.via.is_empty())
+        .then(|| format!("  <author><name>{}</name></author>\n", escape(&l.via)))
+        .unwrap_or_default();
+    let categories: String = l
+        .tags
+        .iter()
+        .map(|t| format!("  <category term=\"{}\"/>\n", escape(t)))
+        .collect();
+
+    format!(
+        "<entry>\n  <id>urn:uuid:{id}</id>\n  <title>{title}</title>\n  <link href=\"{url}\"/>\n  <updated>{updated}</updated>\n{summary}{author}{categories}</entry>\n",
+        id = l.id,
+        title = escape(&l.title),
+        url = escape(&l.url),
+    )
+}
+
+/// Serialize a [`Feed`] to an Atom 1.0 XML document.
+///
+/// Mirrors [`crate::rss::feed_to_rss_xml`]: `title`→`<title>`, `url`→the
+/// entry's `<link>`, `id`→`<id>` (as `urn:uuid:<id>`), `summary`→`<summary>`, `tags`→`<category>`
+/// elements, `via`→`<author>`, and the stored `date` re-emitted as RFC 3339.
+pub fn feed_to_atom_xml(
+    feed: &Feed,
+    site_title: &str,
+    site_link: &str,
+    limit: Option<usize>,
+) -> Result<String> {
+    let n = limit.unwrap_or(feed.links.len()).min(feed.links.len());
+    let title = if feed.title.is_empty() {
+        site_title.to_string()
+    } else {
+        feed.title.clone()
+    };
+    let updated = feed.links[..n]
+        .first()
+        .and_then(|l| parse_local(&l.date))
+        .and_then(|dt| dt.format(&Rfc3339).ok())
+        .unwrap_or_default();
+    let link = escape(site_link);
+    let entries: String = feed.links[..n].iter().map(link_to_atom_entry).collect();
+
+    Ok(format!(
+        "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<feed xmlns=\"http://www.w3.org/2005/Atom\">\n  <title>{title}</title>\n  <link href=\"{link}\"/>\n  <id>{link}</id>\n  <updated>{updated}</updated>\n{entries}</feed>\n",
+        title = escape(&title),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linkleaf_proto::Feed;
+
+    fn sample_feed() -> Feed {
+        let mut f = Feed::default();
+        f.title = "Sample".into();
+        f.version = 1;
+        f.links.push(Link {
+            id: "abc123".into(),
+            title: "Rust".into(),
+            url: "https://www.rust-lang.org".into(),
+            date: "2025-08-23 10:00:00".into(),
+            summary: "The Rust language".into(),
+            tags: vec!["rust".into()],
+            via: "Hacker News".into(),
+        });
+        f
+    }
+
+    #[test]
+    fn feed_to_atom_xml_emits_expected_shape() {
+        let xml = feed_to_atom_xml(&sample_feed(), "Site", "https://example.com", None)
+            .expect("serializes");
+        assert!(xml.contains("<feed xmlns=\"http://www.w3.org/2005/Atom\">"));
+        assert!(xml.contains("<id>urn:uuid:abc123</id>"));
+        assert!(xml.contains("<title>Rust</title>"));
+        assert!(xml.contains("<category term=\"rust\"/>"));
+        assert!(xml.contains("<author><name>Hacker News</name></author>"));
+    }
+
+    #[test]
+    fn export_feed_dispatches_on_format() {
+        let rss = export_feed(&sample_feed(), ExportFormat::Rss, "Site", "https://example.com", None)
+            .expect("serializes");
+        let atom = export_feed(&sample_feed(), ExportFormat::Atom, "Site", "https://example.com", None)
+            .expect("serializes");
+        assert!(rss.contains("<rss"));
+        assert!(atom.contains("<feed xmlns"));
+    }
+}
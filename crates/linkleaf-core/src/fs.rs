@@ -0,0 +1,85 @@
+//! Feed file I/O: atomic read/write of the protobuf-encoded `.pb` feed, plus
+//! an optional post-write hook for publishing pipelines.
+
+use crate::linkleaf_proto::Feed;
+use crate::migrate::migrate;
+use anyhow::{Context, Result, bail};
+use prost::Message;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+/// Read and decode the feed at `path`, migrating it up to the current schema
+/// version (see [`crate::migrate`]) before returning it. The upgraded
+/// version isn't persisted until the next [`write_feed`].
+pub fn read_feed(path: &Path) -> Result<Feed> {
+    let bytes = fs::read(path).with_context(|| format!("failed to read {}", path.display()))?;
+    let feed = Feed::decode(bytes.as_slice())
+        .with_context(|| format!("failed to decode feed: {}", path.display()))?;
+    migrate(feed).with_context(|| format!("failed to migrate feed: {}", path.display()))
+}
+
+/// Encode `feed` and write it to `path` atomically (write to a sibling temp
+/// file, then rename over the destination), creating parent directories as
+/// needed. Returns the feed that was written.
+pub fn write_feed(path: &Path, feed: Feed) -> Result<Feed> {
+    if let Some(dir) = path.parent() {
+        if !dir.as_os_str().is_empty() {
+            fs::create_dir_all(dir)
+                .with_context(|| format!("failed to create directory {}", dir.display()))?;
+        }
+    }
+
+    let tmp_path = path.with_extension("pb.tmp");
+    let mut tmp = fs::File::create(&tmp_path)
+        .with_context(|| format!("failed to create {}", tmp_path.display()))?;
+    tmp.write_all(&feed.encode_to_vec())
+        .with_context(|| format!("failed to write {}", tmp_path.display()))?;
+    tmp.sync_all()
+        .with_context(|| format!("failed to flush {}", tmp_path.display()))?;
+    fs::rename(&tmp_path, path).with_context(|| {
+        format!(
+            "failed to rename {} to {}",
+            tmp_path.display(),
+            path.display()
+        )
+    })?;
+
+    Ok(feed)
+}
+
+/// Run a post-write hook after [`write_feed`] has already renamed the new
+/// feed into place.
+///
+/// `op` is the operation that just completed (`add`, `init`, `sync`, ...)
+/// and `path` is the feed file; both are passed to the hook as positional
+/// arguments. `changed_ids` are the link ids inserted or updated during that
+/// operation, written to the hook's stdin as a newline-separated list so it
+/// can regenerate only the artifacts that changed.
+///
+/// A non-zero exit surfaces as an error. The feed itself is not rolled
+/// back — it was already written before this runs.
+pub fn run_hook(hook: &str, path: &Path, op: &str, changed_ids: &[String]) -> Result<()> {
+    let mut child = Command::new("sh")
+        .arg("-c")
+        .arg(hook)
+        .arg("sh") // becomes $0 inside the hook; path/op land in $1/$2
+        .arg(path)
+        .arg(op)
+        .stdin(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("failed to run hook: {hook}"))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        let _ = stdin.write_all(changed_ids.join("\n").as_bytes());
+    }
+
+    let status = child
+        .wait()
+        .with_context(|| format!("failed to wait on hook: {hook}"))?;
+    if !status.success() {
+        bail!("post-write hook exited with {status}: {hook}");
+    }
+    Ok(())
+}
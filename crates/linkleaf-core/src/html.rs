@@ -0,0 +1,184 @@
+use crate::linkleaf_proto::Feed;
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::Path;
+
+/// Default template, baked into the binary so `html` always has something to
+/// render even when no `--templates` directory is given.
+const DEFAULT_TEMPLATE: &str = include_str!("../templates/feed.html");
+
+/// Lightweight view model for a single link, handed to the template.
+pub struct LinkView {
+    pub title: String,
+    pub url: String,
+    pub date: String,
+    pub summary: String,
+    pub via: String,
+    pub has_tags: bool,
+    pub tags_joined: String,
+}
+
+/// Lightweight view model for the whole page, handed to the template.
+pub struct FeedView {
+    pub title: String,
+    pub count: usize,
+    pub links: Vec<LinkView>,
+}
+
+fn link_to_view(l: &crate::linkleaf_proto::Link) -> LinkView {
+    let has_tags = !l.tags.is_empty();
+    let tags_joined = if has_tags {
+        l.tags.join(", ")
+    } else {
+        String::new()
+    };
+    LinkView {
+        title: l.title.clone(),
+        url: l.url.clone(),
+        date: l.date.clone(),
+        summary: l.summary.clone(),
+        via: l.via.clone(),
+        has_tags,
+        tags_joined,
+    }
+}
+
+/// Render a [`Feed`] into a complete HTML page.
+///
+/// Loads `feed.html` from `templates_dir` at runtime when given (falling back
+/// to the embedded default template otherwise), so users can fully restyle
+/// the generated page without forking the crate.
+///
+/// ## Arguments
+/// - `feed`: The feed to render.
+/// - `custom_title`: Optional page title that overrides the feed's title.
+/// - `templates_dir`: Optional directory containing a `feed.html` template
+///   to use instead of the embedded default. Only `feed.html` is read;
+///   partials (other template files included from it) are not supported.
+/// - `limit`: Optional cap on the number of (newest-first) links to render.
+///
+/// ## Errors
+/// Returns an error if `templates_dir` is given but `feed.html` can't be read
+/// from it.
+pub fn render_feed_html(
+    feed: &Feed,
+    custom_title: Option<String>,
+    templates_dir: Option<&Path>,
+    limit: Option<usize>,
+) -> Result<String> {
+    let title = custom_title.unwrap_or_else(|| {
+        let t = feed.title.trim();
+        if t.is_empty() { "My Links".into() } else { t.into() }
+    });
+
+    let n = limit.unwrap_or(feed.links.len()).min(feed.links.len());
+    let links: Vec<LinkView> = feed.links[..n].iter().map(link_to_view).collect();
+    let view = FeedView {
+        title,
+        count: links.len(),
+        links,
+    };
+
+    let template = match templates_dir {
+        Some(dir) => {
+            let path = dir.join("feed.html");
+            fs::read_to_string(&path)
+                .with_context(|| format!("failed to read template: {}", path.display()))?
+        }
+        None => DEFAULT_TEMPLATE.to_string(),
+    };
+
+    Ok(render(&template, &view))
+}
+
+/// Copy a `static/` subdirectory (custom CSS/JS) from `templates_dir` to
+/// `dest_dir`, if present. A no-op when `templates_dir` is `None` or has no
+/// `static/` subdirectory.
+pub fn copy_static_assets(templates_dir: Option<&Path>, dest_dir: &Path) -> Result<()> {
+    let Some(dir) = templates_dir else {
+        return Ok(());
+    };
+    let src = dir.join("static");
+    if !src.is_dir() {
+        return Ok(());
+    }
+    let dest = dest_dir.join("static");
+    copy_dir_recursive(&src, &dest)
+        .with_context(|| format!("failed to copy {} to {}", src.display(), dest.display()))
+}
+
+fn copy_dir_recursive(src: &Path, dest: &Path) -> Result<()> {
+    fs::create_dir_all(dest)?;
+    for entry in fs::read_dir(src)? {
+        let entry = entry?;
+        let from = entry.path();
+        let to = dest.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir_recursive(&from, &to)?;
+        } else {
+            fs::copy(&from, &to)?;
+        }
+    }
+    Ok(())
+}
+
+/// Expands `{{field}}` placeholders and `{{#each links}}...{{/each}}` /
+/// `{{#if has_tags}}...{{/if}}` blocks against a [`FeedView`].
+///
+/// This is deliberately minimal — just enough logic-less substitution to let
+/// users supply their own `feed.html`, without pulling in a full template
+/// engine dependency.
+fn render(template: &str, view: &FeedView) -> String {
+    let each_start = "{{#each links}}";
+    let each_end = "{{/each}}";
+
+    let out = if let (Some(start), Some(end)) =
+        (template.find(each_start), template.find(each_end))
+    {
+        let before = &template[..start];
+        let item_tpl = &template[start + each_start.len()..end];
+        let after = &template[end + each_end.len()..];
+
+        let mut items = String::new();
+        for link in &view.links {
+            items.push_str(&render_link(item_tpl, link));
+        }
+        format!("{before}{items}{after}")
+    } else {
+        template.to_string()
+    };
+
+    out.replace("{{title}}", &escape(&view.title))
+        .replace("{{count}}", &view.count.to_string())
+}
+
+fn render_link(template: &str, link: &LinkView) -> String {
+    let out = render_if(template, "has_tags", link.has_tags);
+    out.replace("{{title}}", &escape(&link.title))
+        .replace("{{url}}", &escape(&link.url))
+        .replace("{{date}}", &escape(&link.date))
+        .replace("{{summary}}", &escape(&link.summary))
+        .replace("{{via}}", &escape(&link.via))
+        .replace("{{tags_joined}}", &escape(&link.tags_joined))
+}
+
+fn render_if(template: &str, flag: &str, value: bool) -> String {
+    let start_tag = format!("{{{{#if {flag}}}}}");
+    let end_tag = "{{/if}}";
+    match (template.find(&start_tag), template.find(end_tag)) {
+        (Some(start), Some(end)) if end > start => {
+            let before = &template[..start];
+            let inner = &template[start + start_tag.len()..end];
+            let after = &template[end + end_tag.len()..];
+            let body = if value { inner } else { "" };
+            format!("{before}{body}{after}")
+        }
+        _ => template.to_string(),
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
@@ -0,0 +1,203 @@
+//! Import entries from remote RSS/Atom/JSON Feed documents, parsed with
+//! `feed-rs`, and fold them into the local `.pb` feed in a single
+//! read-modify-write pass (not one read/write per entry).
+//!
+//! [`crate::pull`] delegates to [`import_one`] for the actual fetch/parse/
+//! merge and layers a persisted subscription list on top, so a bare `pull`
+//! with no URLs can refresh every previously-tracked feed.
+
+use crate::linkleaf_proto::Feed;
+use crate::{IdStrategy, TS_FMT, insert_new_link_front, update_link_in_place};
+use anyhow::{Context, Result};
+use feed_rs::model::Entry;
+use feed_rs::parser;
+use std::path::Path;
+use time::OffsetDateTime;
+
+/// Result of importing one remote feed document.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ImportStats {
+    pub inserted: usize,
+    pub updated: usize,
+}
+
+/// One feed entry, already mapped onto the fields a local link needs.
+struct MappedEntry {
+    title: String,
+    url: String,
+    summary: Option<String>,
+    tags: Vec<String>,
+    via: Option<String>,
+    id_strategy: IdStrategy,
+    date: String,
+}
+
+/// Map a parsed [`Entry`] onto the `(title, url, summary, tags)` fields a
+/// local link needs. Returns `None` for entries missing a title or a link,
+/// since linkleaf requires both.
+pub(crate) fn entry_fields(entry: &Entry) -> Option<(String, String, Option<String>, Vec<String>)> {
+    let title = entry.title.as_ref()?.content.clone();
+    let url = entry.links.first()?.href.clone();
+    let summary = entry
+        .summary
+        .as_ref()
+        .map(|s| s.content.clone())
+        .or_else(|| entry.content.as_ref().and_then(|c| c.body.clone()));
+    let tags = entry.categories.iter().map(|c| c.term.clone()).collect();
+    Some((title, url, summary, tags))
+}
+
+/// Pick the [`IdStrategy`] for an incoming entry: reuse the feed's own id
+/// when it gave one, otherwise fall back to [`IdStrategy::ContentHash`].
+pub(crate) fn entry_id_strategy(entry: &Entry) -> IdStrategy {
+    if entry.id.is_empty() {
+        IdStrategy::ContentHash
+    } else {
+        IdStrategy::Provided(entry.id.clone())
+    }
+}
+
+/// Convert an entry's `published`/`updated` timestamp into `TS_FMT`, falling
+/// back to "now" in local time when the entry carries neither.
+pub(crate) fn entry_date(entry: &Entry) -> Result<String> {
+    let local_offset = OffsetDateTime::now_local()
+        .map(|n| n.offset())
+        .unwrap_or(time::UtcOffset::UTC);
+
+    let odt = match entry.published.or(entry.updated) {
+        Some(dt) => time::OffsetDateTime::from_unix_timestamp(dt.timestamp())
+            .map_err(|e| anyhow::anyhow!("invalid entry timestamp: {e}"))?
+            .to_offset(local_offset),
+        None => OffsetDateTime::now_local().unwrap_or_else(|_| OffsetDateTime::now_utc()),
+    };
+    odt.format(TS_FMT)
+        .map_err(|e| anyhow::anyhow!("failed to format entry timestamp: {e}"))
+}
+
+/// Map every entry in a parsed `feed-rs` document onto the fields a local
+/// link needs, skipping entries missing a title or a link.
+pub(crate) fn map_entries(parsed: &feed_rs::model::Feed) -> Result<Vec<MappedEntry>> {
+    let feed_title = parsed
+        .title
+        .as_ref()
+        .map(|t| t.content.clone())
+        .filter(|t| !t.is_empty());
+
+    let mut mapped = Vec::with_capacity(parsed.entries.len());
+    for entry in &parsed.entries {
+        let Some((title, url, summary, tags)) = entry_fields(entry) else {
+            continue;
+        };
+        mapped.push(MappedEntry {
+            title,
+            url,
+            summary,
+            tags,
+            via: feed_title.clone(),
+            id_strategy: entry_id_strategy(entry),
+            date: entry_date(entry)?,
+        });
+    }
+    Ok(mapped)
+}
+
+/// Fold every mapped entry into `feed` in memory, reusing the same
+/// insert-or-update-by-url semantics as [`crate::add`], without touching
+/// disk per entry — callers read the feed once and write it once.
+pub(crate) fn merge_entries(feed: &mut Feed, entries: Vec<MappedEntry>) -> ImportStats {
+    let mut stats = ImportStats::default();
+    for e in entries {
+        if let Some(pos) = feed.links.iter().position(|l| l.url == e.url) {
+            update_link_in_place(feed, pos, e.title, e.url, e.date, e.summary, e.tags, e.via);
+            stats.updated += 1;
+        } else {
+            let id = e.id_strategy.generate(&e.url, &e.date);
+            insert_new_link_front(feed, id, e.title, e.url, e.date, e.summary, e.tags, e.via);
+            stats.inserted += 1;
+        }
+    }
+    stats
+}
+
+/// Fetch `url`, parse it with `feed-rs`, and fold every entry into the feed
+/// at `file` in a single read-modify-write pass, reusing the same
+/// dedupe-by-URL semantics as [`crate::add`] so re-importing the same feed
+/// updates rather than duplicates.
+pub fn import_one(file: &Path, url: &str) -> Result<ImportStats> {
+    let body = ureq::get(url)
+        .call()
+        .with_context(|| format!("failed to fetch feed: {url}"))?
+        .into_reader();
+    let parsed = parser::parse(body).with_context(|| format!("failed to parse feed: {url}"))?;
+    let entries = map_entries(&parsed)?;
+
+    let mut feed = match crate::fs::read_feed(file) {
+        Ok(f) => f,
+        Err(err) if crate::is_not_found(&err) => {
+            let mut f = Feed::default();
+            f.version = 1;
+            f
+        }
+        Err(err) => return Err(err),
+    };
+
+    let stats = merge_entries(&mut feed, entries);
+    crate::fs::write_feed(file, feed)?;
+    Ok(stats)
+}
+
+/// Fetch and fold every URL in `urls` into `file`, summing the per-feed stats.
+pub fn import(file: &Path, urls: &[String]) -> Result<ImportStats> {
+    let mut total = ImportStats::default();
+    for url in urls {
+        let stats = import_one(file, url)?;
+        total.inserted += stats.inserted;
+        total.updated += stats.updated;
+    }
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_rss() -> &'static str {
+        r#"<?xml version="1.0"?>
+        <rss version="2.0"><channel>
+        <title>My Feed</title>
+        <item>
+          <title>Hello World</title>
+          <link>https://example.com/hello</link>
+          <description>a post</description>
+          <category>rust</category>
+          <pubDate>Mon, 01 Jan 2024 00:00:00 GMT</pubDate>
+        </item>
+        </channel></rss>"#
+    }
+
+    #[test]
+    fn entry_fields_maps_title_link_summary_and_tags() {
+        let parsed = parser::parse(sample_rss().as_bytes()).unwrap();
+        let entry = &parsed.entries[0];
+        let (title, url, summary, tags) = entry_fields(entry).unwrap();
+        assert_eq!(title, "Hello World");
+        assert_eq!(url, "https://example.com/hello");
+        assert_eq!(summary.as_deref(), Some("a post"));
+        assert_eq!(tags, vec!["rust"]);
+    }
+
+    #[test]
+    fn entry_date_uses_published_timestamp_not_now() {
+        let parsed = parser::parse(sample_rss().as_bytes()).unwrap();
+        let entry = &parsed.entries[0];
+        let date = entry_date(entry).unwrap();
+        assert!(date.starts_with("2024-01-01"), "got {date}");
+    }
+
+    #[test]
+    fn entry_fields_skips_entry_missing_link() {
+        let rss = r#"<rss><channel><item><title>No URL</title></item></channel></rss>"#;
+        let parsed = parser::parse(rss.as_bytes()).unwrap();
+        assert!(entry_fields(&parsed.entries[0]).is_none());
+    }
+}
@@ -0,0 +1,129 @@
+use crate::TS_FMT;
+use crate::linkleaf_proto::{Feed, Link};
+use crate::sort_links_by_date_desc;
+use anyhow::Result;
+use serde::Serialize;
+use time::PrimitiveDateTime;
+use time::format_description::well_known::Rfc3339;
+
+#[derive(Serialize)]
+struct JsonFeedItem {
+    id: String,
+    url: String,
+    title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    date_published: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct JsonFeedDoc {
+    version: &'static str,
+    title: String,
+    home_page_url: String,
+    items: Vec<JsonFeedItem>,
+}
+
+fn date_published(date: &str) -> Option<String> {
+    let naive = PrimitiveDateTime::parse(date, TS_FMT).ok()?;
+    let local_off = time::OffsetDateTime::now_local().ok()?.offset();
+    naive.assume_offset(local_off).format(&Rfc3339).ok()
+}
+
+fn link_to_item(l: &Link) -> JsonFeedItem {
+    JsonFeedItem {
+        id: format!("urn:uuid:{}", l.id),
+        url: l.url.clone(),
+        title: l.title.clone(),
+        content_text: (!l.summary.is_empty()).then(|| l.summary.clone()),
+        external_url: (!l.via.is_empty()).then(|| l.via.clone()),
+        date_published: date_published(&l.date),
+        tags: l.tags.clone(),
+    }
+}
+
+/// Serialize a [`Feed`] as a JSON Feed 1.1 document (<https://jsonfeed.org/version/1.1>).
+///
+/// Mirrors [`crate::feed_to_rss_xml`]: `site_title`/`site_link` are
+/// channel-level fallbacks when `feed.title` is empty, `site_link` doubles
+/// as the required top-level `home_page_url`, links are sorted newest-first
+/// via [`crate::sort_links_by_date_desc`], and `limit`, if given, caps the
+/// number of items emitted.
+pub fn feed_to_json_feed(
+    feed: &Feed,
+    site_title: &str,
+    site_link: &str,
+    limit: Option<usize>,
+) -> Result<String> {
+    let mut links = feed.links.clone();
+    sort_links_by_date_desc(&mut links);
+    let n = limit.unwrap_or(links.len()).min(links.len());
+
+    let doc = JsonFeedDoc {
+        version: "https://jsonfeed.org/version/1.1",
+        title: if feed.title.is_empty() {
+            site_title.to_string()
+        } else {
+            feed.title.clone()
+        },
+        home_page_url: site_link.to_string(),
+        items: links[..n].iter().map(link_to_item).collect(),
+    };
+    Ok(serde_json::to_string_pretty(&doc)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linkleaf_proto::Feed;
+
+    fn sample_feed() -> Feed {
+        let mut f = Feed::default();
+        f.title = "Sample".into();
+        f.version = 1;
+        f.links.push(Link {
+            id: "abc123".into(),
+            title: "Rust".into(),
+            url: "https://www.rust-lang.org".into(),
+            date: "2025-08-23 10:00:00".into(),
+            summary: "The Rust language".into(),
+            tags: vec!["rust".into()],
+            via: "".into(),
+        });
+        f
+    }
+
+    #[test]
+    fn feed_to_json_feed_emits_expected_shape() {
+        let json = feed_to_json_feed(&sample_feed(), "Site", "https://example.com", None)
+            .expect("serializes");
+        assert!(json.contains("\"version\": \"https://jsonfeed.org/version/1.1\""));
+        assert!(json.contains("\"home_page_url\": \"https://example.com\""));
+        assert!(json.contains("\"id\": \"urn:uuid:abc123\""));
+        assert!(json.contains("\"content_text\": \"The Rust language\""));
+    }
+
+    #[test]
+    fn feed_to_json_feed_sorts_newest_first_and_respects_limit() {
+        let mut f = sample_feed();
+        f.links.push(Link {
+            id: "def456".into(),
+            title: "Newer".into(),
+            url: "https://example.com/newer".into(),
+            date: "2025-09-01 00:00:00".into(),
+            summary: "".into(),
+            tags: vec![],
+            via: "".into(),
+        });
+
+        let json = feed_to_json_feed(&f, "Site", "https://example.com", Some(1))
+            .expect("serializes");
+        assert!(json.contains("\"id\": \"urn:uuid:def456\""));
+        assert!(!json.contains("\"id\": \"urn:uuid:abc123\""));
+    }
+}
@@ -1,12 +1,28 @@
+pub mod check;
+pub mod etag;
+pub mod export;
 pub mod fs;
+pub mod html;
+pub mod import;
+pub mod json;
+pub mod migrate;
+pub mod pull;
+pub mod rss;
 pub mod validation;
+pub mod xdg;
 pub mod linkleaf_proto {
     include!(concat!(env!("OUT_DIR"), "/linkleaf.v1.rs"));
 }
 
+pub use etag::etag_for;
+pub use html::render_feed_html;
+pub use json::feed_to_json_feed;
+pub use rss::feed_to_rss_xml;
+
 use crate::fs::{read_feed, write_feed};
 use crate::linkleaf_proto::{Feed, Link};
 use anyhow::Result;
+use sha2::{Digest, Sha256};
 use std::path::Path;
 use time::{Date, OffsetDateTime, PrimitiveDateTime, macros::format_description};
 use uuid::Uuid;
@@ -14,6 +30,100 @@ use uuid::Uuid;
 const TS_FMT: &[time::format_description::FormatItem<'_>] =
     format_description!("[year]-[month]-[day] [hour]:[minute]:[second]");
 
+/// Minimal view of a link's identifying fields, handed to an [`IdGenerator`].
+pub struct LinkSeed<'a> {
+    pub url: &'a str,
+    pub date: &'a str,
+}
+
+/// Pluggable id-generation scheme. [`IdStrategy`] is the menu of generators
+/// `add` accepts by name; implement this trait directly when embedding
+/// linkleaf-core in a larger tool that needs a custom id scheme.
+pub trait IdGenerator {
+    fn id_for(&self, seed: &LinkSeed) -> String;
+}
+
+/// The historical default: a random `Uuid::new_v4`, ignoring `seed` entirely.
+pub struct RandomIdGenerator;
+
+impl IdGenerator for RandomIdGenerator {
+    fn id_for(&self, _seed: &LinkSeed) -> String {
+        Uuid::new_v4().to_string()
+    }
+}
+
+/// Fixed namespace UUID linkleaf derives deterministic ids under; arbitrary,
+/// but must never change once links have been minted against it.
+const LINKLEAF_NAMESPACE: Uuid = Uuid::from_bytes([
+    0x6d, 0xc5, 0x8a, 0x1e, 0x5f, 0x3b, 0x4a, 0x9c, 0x8e, 0x2d, 0x1a, 0x7f, 0x3c, 0x9b, 0x5e, 0x42,
+]);
+
+/// Lowercase the scheme and host and strip a trailing slash from the path, so
+/// `HTTP://Example.com/a/` and `http://example.com/a` normalize identically.
+fn normalize_url(url: &str) -> String {
+    let trimmed = url.trim();
+    let (scheme, rest) = match trimmed.find("://") {
+        Some(i) => (&trimmed[..i + 3], &trimmed[i + 3..]),
+        None => ("", trimmed),
+    };
+    let (host, path) = match rest.find('/') {
+        Some(i) => (&rest[..i], &rest[i..]),
+        None => (rest, ""),
+    };
+    let path = path.strip_suffix('/').unwrap_or(path);
+    format!("{}{}{path}", scheme.to_lowercase(), host.to_lowercase())
+}
+
+/// Deterministic UUIDv5 derived from [`LINKLEAF_NAMESPACE`], hashed over the
+/// normalized URL. Re-adding or importing the same URL from any machine
+/// converges on the same id, which is what makes cross-device dedup and
+/// merging feeds from multiple sources reliable.
+pub struct UrlNamespaceIdGenerator;
+
+impl IdGenerator for UrlNamespaceIdGenerator {
+    fn id_for(&self, seed: &LinkSeed) -> String {
+        Uuid::new_v5(&LINKLEAF_NAMESPACE, normalize_url(seed.url).as_bytes()).to_string()
+    }
+}
+
+/// Strategy used to mint an `id` for a newly inserted link when no explicit
+/// `id` override is given to [`add`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum IdStrategy {
+    /// A random `Uuid::new_v4`. The historical, non-deterministic default.
+    #[default]
+    Uuid,
+    /// `sha256(url|date)`, truncated to the first 12 hex characters.
+    ///
+    /// Deterministic: adding the same url/date pair on two machines (or via
+    /// two separate feed imports) converges on the same `id`, which is what
+    /// makes merging feeds from multiple sources idempotent.
+    ContentHash,
+    /// Deterministic UUIDv5 over the normalized URL alone (see
+    /// [`UrlNamespaceIdGenerator`]). Unlike `ContentHash`, this ignores
+    /// `date`, so the same URL converges on the same id even when imported
+    /// on different days.
+    UuidV5,
+    /// Use an id the caller already has in hand (e.g. one carried by an
+    /// imported feed entry) instead of generating one.
+    Provided(String),
+}
+
+impl IdStrategy {
+    fn generate(self, url: &str, date: &str) -> String {
+        let seed = LinkSeed { url, date };
+        match self {
+            IdStrategy::Uuid => RandomIdGenerator.id_for(&seed),
+            IdStrategy::ContentHash => {
+                let digest = Sha256::digest(format!("{url}|{date}").as_bytes());
+                format!("{digest:x}")[..12].to_string()
+            }
+            IdStrategy::UuidV5 => UrlNamespaceIdGenerator.id_for(&seed),
+            IdStrategy::Provided(id) => id,
+        }
+    }
+}
+
 fn is_not_found(err: &anyhow::Error) -> bool {
     err.downcast_ref::<std::io::Error>()
         .map(|e| e.kind() == std::io::ErrorKind::NotFound)
@@ -119,6 +229,7 @@ fn insert_new_link_front(
 ///     ["rust", "async", "tokio"],
 ///     None,
 ///     None, // no id -> create (may update if URL already exists)
+///     IdStrategy::Uuid,
 /// )?;
 ///
 /// // Update the same link by id (upsert)
@@ -131,6 +242,7 @@ fn insert_new_link_front(
 ///     [],                 // no tags change
 ///     None,
 ///     Some(_id),          // provide id -> update or insert with that id
+///     IdStrategy::Uuid,
 /// )?;
 ///
 /// assert_eq!(a2.id, a.id);
@@ -142,6 +254,8 @@ fn insert_new_link_front(
 /// - Providing an `id` gives the item a stable identity; updates by `id` will also update
 ///   the stored `url` to the new value you pass.
 /// - `date` is always set to “today” in local time on both create and update.
+/// - `id_strategy` only applies when inserting a new link with no explicit `id`; see
+///   [`IdStrategy`].
 pub fn add<P, S, T>(
     file: P,
     title: S,
@@ -150,13 +264,13 @@ pub fn add<P, S, T>(
     tags: T,
     via: Option<S>,
     id: Option<Uuid>,
+    id_strategy: IdStrategy,
 ) -> Result<Link>
 where
     P: AsRef<Path>,
     S: Into<String>,
     T: IntoIterator<Item = S>,
 {
-    let file = file.as_ref();
     // compute local timestamp once
     let local_now = OffsetDateTime::now_local()
         .map_err(|e| anyhow::anyhow!("failed to get local time offset: {e}"))?;
@@ -164,6 +278,29 @@ where
         .format(TS_FMT)
         .map_err(|e| anyhow::anyhow!("failed to format timestamp: {e}"))?;
 
+    add_with_date(file, title, url, summary, tags, via, id, id_strategy, date)
+}
+
+/// Same as [`add`], but with an explicit `date` instead of "now" in local
+/// time. [`add`] delegates here after formatting the current local time.
+pub(crate) fn add_with_date<P, S, T>(
+    file: P,
+    title: S,
+    url: S,
+    summary: Option<S>,
+    tags: T,
+    via: Option<S>,
+    id: Option<Uuid>,
+    id_strategy: IdStrategy,
+    date: String,
+) -> Result<Link>
+where
+    P: AsRef<Path>,
+    S: Into<String>,
+    T: IntoIterator<Item = S>,
+{
+    let file = file.as_ref();
+
     // read or init feed
     let mut feed = match read_feed(file) {
         Ok(f) => f,
@@ -230,7 +367,7 @@ where
                 tracing::info!(id = %item.id, "inserted new link with explicit id");
                 item
             } else {
-                let uid = Uuid::new_v4().to_string();
+                let uid = id_strategy.generate(&url, &date);
                 let item = insert_new_link_front(
                     &mut feed,
                     uid,
@@ -261,6 +398,10 @@ where
 /// Calls [`read_feed`] on the provided path and returns the parsed [`Feed`]. If tags and/or
 /// date filters are provided it filters the resulting [`Feed`].
 ///
+/// `date` matches a single day exactly; `since`/`until` instead filter a
+/// half-open range (`since <= link date < until`) and can be combined with
+/// each other (or with `date`, though that's rarely useful).
+///
 /// ## Arguments
 /// - `file`: Path to the `.pb` feed file.
 ///
@@ -277,7 +418,7 @@ where
 /// use linkleaf_core::*;
 ///
 /// let path = PathBuf::from("mylinks.pb");
-/// let feed = list(&path, None, None)?;
+/// let feed = list(&path, None, None, None, None)?;
 /// println!("Title: {}, links: {}", feed.title, feed.links.len());
 /// Ok::<(), anyhow::Error>(())
 /// ```
@@ -285,6 +426,8 @@ pub fn list<P: AsRef<Path>>(
     file: P,
     tags: Option<Vec<String>>,
     date: Option<Date>,
+    since: Option<Date>,
+    until: Option<Date>,
 ) -> Result<Feed> {
     let file = file.as_ref();
     let mut feed = read_feed(file)?;
@@ -305,11 +448,13 @@ pub fn list<P: AsRef<Path>>(
             None => true,
         };
 
-        let date_ok = match date {
-            Some(p) => PrimitiveDateTime::parse(&l.date, TS_FMT)
-                .map(|dt| dt.date() == p)
-                .unwrap_or(false),
-            None => true,
+        let date_ok = match PrimitiveDateTime::parse(&l.date, TS_FMT).map(|dt| dt.date()) {
+            Ok(d) => {
+                date.map_or(true, |p| d == p)
+                    && since.map_or(true, |s| d >= s)
+                    && until.map_or(true, |u| d < u)
+            }
+            Err(_) => date.is_none() && since.is_none() && until.is_none(),
         };
 
         tag_ok && date_ok
@@ -318,9 +463,29 @@ pub fn list<P: AsRef<Path>>(
     Ok(feed)
 }
 
+/// Sort `links` newest-first by their `date` (parsed with `TS_FMT`). Links
+/// whose `date` doesn't parse sort last, after every dated link.
+///
+/// Shared by the feed generators ([`crate::feed_to_rss_xml`],
+/// [`crate::feed_to_json_feed`]) so "newest N links" means the same thing
+/// everywhere, instead of each caller trusting the feed's stored order.
+pub fn sort_links_by_date_desc(links: &mut [Link]) {
+    links.sort_by(|a, b| {
+        match (
+            PrimitiveDateTime::parse(&a.date, TS_FMT),
+            PrimitiveDateTime::parse(&b.date, TS_FMT),
+        ) {
+            (Ok(da), Ok(db)) => db.cmp(&da),
+            (Ok(_), Err(_)) => std::cmp::Ordering::Less,
+            (Err(_), Ok(_)) => std::cmp::Ordering::Greater,
+            (Err(_), Err(_)) => std::cmp::Ordering::Equal,
+        }
+    });
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{add, list};
+    use super::{IdStrategy, add, list, sort_links_by_date_desc};
     use crate::fs::{read_feed, write_feed};
     use crate::linkleaf_proto::{Feed, Link};
     use anyhow::Result;
@@ -373,6 +538,7 @@ mod tests {
             vec!["rust", "async", "tokio"],
             None,         // via -> ""
             None::<Uuid>, // id -> generated
+            IdStrategy::Uuid,
         )?;
 
         // File exists and can be read; version initialized to 1
@@ -406,12 +572,13 @@ mod tests {
             Some("x,y".into()),
             Some("via".into()),
             Some(wanted),
+            IdStrategy::Uuid,
         )?;
 
         assert_eq!(created.id, wanted.to_string());
 
         // list(None, None) returns everything; first item is the one we just added
-        let feed = list(&file, None, None)?;
+        let feed = list(&file, None, None, None, None)?;
         assert_eq!(feed.links.len(), 1);
         assert_eq!(feed.links[0].id, wanted.to_string());
         Ok(())
@@ -431,6 +598,7 @@ mod tests {
             tags,
             None,
             None::<Uuid>,
+            IdStrategy::Uuid,
         )?;
         let _b = add(
             file.clone(),
@@ -440,6 +608,7 @@ mod tests {
             Some("beta".into()),
             None,
             None,
+            IdStrategy::Uuid,
         )?;
 
         // Update by id of 'a': title/url/tags/via/summary overwritten, item moves to front
@@ -451,6 +620,7 @@ mod tests {
             ["rust", "updated"],
             Some("HN".into()),
             Some(Uuid::parse_str(&a.id)?),
+            IdStrategy::Uuid,
         )?;
         assert_eq!(updated.id, a.id);
         assert_eq!(updated.title, "First (updated)");
@@ -459,7 +629,7 @@ mod tests {
         assert_eq!(updated.via, "HN");
         assert_eq!(updated.tags, vec!["rust", "updated"]);
 
-        let feed = list(&file, None, None)?;
+        let feed = list(&file, None, None, None, None)?;
         assert_eq!(feed.links.len(), 2);
         assert_eq!(feed.links[0].id, a.id, "updated item should be at index 0");
         assert_eq!(feed.links[0].title, "First (updated)");
@@ -479,6 +649,7 @@ mod tests {
             None,
             None,
             None,
+            IdStrategy::Uuid,
         )?;
 
         // Same URL, id=None => update-in-place (but moved to front) and id stays the same
@@ -490,10 +661,11 @@ mod tests {
             ["t1", "t2"],
             None,
             None,
+            IdStrategy::Uuid,
         )?;
         assert_eq!(updated.id, first.id);
 
-        let feed = list(&file, None, None)?;
+        let feed = list(&file, None, None, None, None)?;
         assert_eq!(feed.links.len(), 1);
         assert_eq!(feed.links[0].title, "Original (updated)");
         assert_eq!(feed.links[0].tags, vec!["t1", "t2"]);
@@ -513,6 +685,7 @@ mod tests {
             None,
             None,
             None,
+            IdStrategy::Uuid,
         )?;
         let b = add(
             file.clone(),
@@ -522,9 +695,10 @@ mod tests {
             None,
             None,
             None,
+            IdStrategy::Uuid,
         )?;
 
-        let feed = list(&file, None, None)?;
+        let feed = list(&file, None, None, None, None)?;
         assert_eq!(feed.links.len(), 2);
         assert_eq!(feed.links[0].id, b.id, "new item should be at front");
         Ok(())
@@ -546,6 +720,7 @@ mod tests {
             None,
             None,
             None,
+            IdStrategy::Uuid,
         )
         .unwrap_err();
 
@@ -580,7 +755,7 @@ mod tests {
         );
         write_feed(&file, mk_feed(vec![l2.clone(), l1.clone()]))?;
 
-        let feed = list(&file, None, None)?;
+        let feed = list(&file, None, None, None, None)?;
         assert_eq!(feed.links.len(), 2);
         // Order is preserved from the stored feed for list()
         assert_eq!(feed.links[0].id, l2.id);
@@ -588,6 +763,19 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn sort_links_by_date_desc_reorders_newest_first_and_sinks_unparsable() {
+        let mut links = vec![
+            mk_link("1", "One", "https://1/", "2025-01-02 12:00:00", &[], "", ""),
+            mk_link("2", "Two", "https://2/", "2025-01-03 09:30:15", &[], "", ""),
+            mk_link("3", "Bad", "https://3/", "not-a-date", &[], "", ""),
+        ];
+        sort_links_by_date_desc(&mut links);
+        assert_eq!(links[0].id, "2");
+        assert_eq!(links[1].id, "1");
+        assert_eq!(links[2].id, "3", "unparsable dates sort last");
+    }
+
     #[test]
     fn list_filters_by_tag_case_insensitive_any_match() -> Result<()> {
         let dir = tempdir()?;
@@ -614,16 +802,16 @@ mod tests {
         write_feed(&file, mk_feed(vec![l1.clone(), l2.clone()]))?;
 
         // ANY-of semantics, case-insensitive
-        let feed_tokio = list(&file, Some(vec!["tokio".into()]), None)?;
+        let feed_tokio = list(&file, Some(vec!["tokio".into()]), None, None, None)?;
         assert_eq!(feed_tokio.links.len(), 1);
         assert_eq!(feed_tokio.links[0].id, l2.id);
 
-        let feed_async = list(&file, Some(vec!["ASYNC".into()]), None)?;
+        let feed_async = list(&file, Some(vec!["ASYNC".into()]), None, None, None)?;
         assert_eq!(feed_async.links.len(), 1);
         assert_eq!(feed_async.links[0].id, l1.id);
 
         // Multiple needles -> still "any"
-        let feed_multi = list(&file, Some(vec!["zzz".into(), "rust".into()]), None)?;
+        let feed_multi = list(&file, Some(vec!["zzz".into(), "rust".into()]), None, None, None)?;
         assert_eq!(feed_multi.links.len(), 1);
         assert_eq!(feed_multi.links[0].id, l1.id);
 
@@ -655,14 +843,97 @@ mod tests {
         );
         write_feed(&file, mk_feed(vec![l1.clone(), l2.clone()]))?;
 
-        let filtered = list(&file, None, Some(date!(2025 - 01 - 03)))?;
+        let filtered = list(&file, None, Some(date!(2025 - 01 - 03)), None, None)?;
         assert_eq!(filtered.links.len(), 1);
         assert_eq!(filtered.links[0].id, l2.id);
 
-        let filtered2 = list(&file, None, Some(date!(2025 - 01 - 02)))?;
+        let filtered2 = list(&file, None, Some(date!(2025 - 01 - 02)), None, None)?;
         assert_eq!(filtered2.links.len(), 1);
         assert_eq!(filtered2.links[0].id, l1.id);
 
         Ok(())
     }
+
+    #[test]
+    fn list_filters_by_since_until_half_open_range() -> Result<()> {
+        let dir = tempdir()?;
+        let file = dir.path().join("feed.pb");
+
+        let l1 = mk_link(
+            "1",
+            "Jan02",
+            "https://1/",
+            "2025-01-02 00:00:00",
+            &[],
+            "",
+            "",
+        );
+        let l2 = mk_link(
+            "2",
+            "Jan03",
+            "https://2/",
+            "2025-01-03 23:59:59",
+            &[],
+            "",
+            "",
+        );
+        let l3 = mk_link(
+            "3",
+            "Jan05",
+            "https://3/",
+            "2025-01-05 12:00:00",
+            &[],
+            "",
+            "",
+        );
+        write_feed(&file, mk_feed(vec![l1.clone(), l2.clone(), l3.clone()]))?;
+
+        // [Jan 02, Jan 05) includes l1 and l2 but not l3, whose date is the
+        // exclusive upper bound.
+        let ranged = list(
+            &file,
+            None,
+            None,
+            Some(date!(2025 - 01 - 02)),
+            Some(date!(2025 - 01 - 05)),
+        )?;
+        assert_eq!(ranged.links.len(), 2);
+        assert!(ranged.links.iter().any(|l| l.id == l1.id));
+        assert!(ranged.links.iter().any(|l| l.id == l2.id));
+
+        // A range with no matches returns an empty feed, not an error.
+        let empty = list(
+            &file,
+            None,
+            None,
+            Some(date!(2025 - 02 - 01)),
+            Some(date!(2025 - 03 - 01)),
+        )?;
+        assert!(empty.links.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn normalize_url_lowercases_host_and_strips_trailing_slash() {
+        assert_eq!(
+            super::normalize_url("HTTP://Example.com/a/"),
+            "http://example.com/a"
+        );
+        assert_eq!(
+            super::normalize_url("http://example.com/a"),
+            "http://example.com/a"
+        );
+    }
+
+    #[test]
+    fn uuid_v5_strategy_is_deterministic_and_ignores_date() {
+        let a = IdStrategy::UuidV5.generate("https://example.com/post", "2025-01-01 00:00:00");
+        let b = IdStrategy::UuidV5.generate("https://example.com/post", "2025-06-15 12:00:00");
+        assert_eq!(a, b, "same url should converge on the same id regardless of date");
+
+        let different_url =
+            IdStrategy::UuidV5.generate("https://example.com/other", "2025-01-01 00:00:00");
+        assert_ne!(a, different_url);
+    }
 }
@@ -0,0 +1,114 @@
+//! Schema migrations: [`crate::fs::read_feed`] runs every feed through
+//! [`migrate`] before handing it back, so older `.pb` files stay readable as
+//! the schema evolves. Each step upgrades from one source `version` to the
+//! next; adding a future `version = 2` is a matter of appending one step and
+//! bumping [`CURRENT_VERSION`].
+
+use crate::linkleaf_proto::Feed;
+use crate::{IdGenerator, LinkSeed, UrlNamespaceIdGenerator};
+use anyhow::{Result, bail};
+use std::collections::HashSet;
+
+/// The schema version new feeds are created at and existing feeds are
+/// migrated up to.
+pub const CURRENT_VERSION: u32 = 1;
+
+type MigrationStep = fn(Feed) -> Result<Feed>;
+
+/// Steps keyed by the version they upgrade *from*, applied in order until
+/// `feed.version == CURRENT_VERSION`.
+const STEPS: &[(u32, MigrationStep)] = &[(0, migrate_from_v0)];
+
+/// Run `feed` through every migration step needed to bring it from its
+/// stored `version` up to [`CURRENT_VERSION`]. A no-op when the feed is
+/// already current.
+pub fn migrate(mut feed: Feed) -> Result<Feed> {
+    while feed.version < CURRENT_VERSION {
+        let Some((_, step)) = STEPS.iter().find(|(from, _)| *from == feed.version) else {
+            bail!("no migration step from feed version {}", feed.version);
+        };
+        feed = step(feed)?;
+    }
+    Ok(feed)
+}
+
+/// Bring a legacy, unversioned (`version == 0`) feed up to version 1:
+/// normalize legacy date formats, backfill missing ids with the
+/// deterministic url-based generator, and de-duplicate each link's tags.
+fn migrate_from_v0(mut feed: Feed) -> Result<Feed> {
+    for link in &mut feed.links {
+        if link.id.is_empty() {
+            link.id = UrlNamespaceIdGenerator.id_for(&LinkSeed {
+                url: &link.url,
+                date: &link.date,
+            });
+        }
+        link.date = normalize_legacy_date(&link.date);
+
+        let mut seen = HashSet::new();
+        link.tags.retain(|t| seen.insert(t.clone()));
+    }
+    feed.version = 1;
+    Ok(feed)
+}
+
+/// Best-effort normalization of a legacy `YYYY-MM-DD` stamp (no time
+/// component) into the crate's `TS_FMT` (`YYYY-MM-DD HH:MM:SS`). Anything
+/// else is left untouched.
+fn normalize_legacy_date(date: &str) -> String {
+    if date.len() == 10 && date.chars().filter(|&c| c == '-').count() == 2 {
+        format!("{date} 00:00:00")
+    } else {
+        date.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linkleaf_proto::Link;
+
+    fn mk_link(id: &str, date: &str, tags: &[&str]) -> Link {
+        Link {
+            id: id.to_string(),
+            title: "t".into(),
+            url: "https://example.com/a".into(),
+            date: date.to_string(),
+            summary: String::new(),
+            tags: tags.iter().map(|s| s.to_string()).collect(),
+            via: String::new(),
+        }
+    }
+
+    #[test]
+    fn migrate_is_noop_on_current_version() {
+        let mut feed = Feed::default();
+        feed.version = CURRENT_VERSION;
+        feed.links.push(mk_link("abc", "2025-01-01 00:00:00", &["x"]));
+        let migrated = migrate(feed.clone()).unwrap();
+        assert_eq!(migrated, feed);
+    }
+
+    #[test]
+    fn migrate_from_v0_backfills_ids_normalizes_dates_and_dedupes_tags() {
+        let mut feed = Feed::default();
+        feed.version = 0;
+        feed.links.push(mk_link("", "2025-01-01", &["rust", "rust", "web"]));
+
+        let migrated = migrate(feed).unwrap();
+        assert_eq!(migrated.version, CURRENT_VERSION);
+        assert!(!migrated.links[0].id.is_empty());
+        assert_eq!(migrated.links[0].date, "2025-01-01 00:00:00");
+        assert_eq!(migrated.links[0].tags, vec!["rust", "web"]);
+    }
+
+    #[test]
+    fn migrate_from_v0_keeps_existing_id() {
+        let mut feed = Feed::default();
+        feed.version = 0;
+        feed.links.push(mk_link("already-set", "2025-01-01 12:00:00", &[]));
+
+        let migrated = migrate(feed).unwrap();
+        assert_eq!(migrated.links[0].id, "already-set");
+    }
+}
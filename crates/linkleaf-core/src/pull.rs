@@ -0,0 +1,86 @@
+//! Feed aggregation: fetch remote RSS/Atom/JSON feeds and merge their
+//! entries into a local `.pb` feed, delegating the actual fetch/parse/merge
+//! to [`crate::import::import_one`].
+//!
+//! Unlike a bare `import`, `pull` can also persist the list of subscribed
+//! feed URLs, so a `pull` with no arguments refreshes everything it has
+//! ever been pointed at.
+
+use crate::import::import_one;
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Path of the tracked subscription list, one URL per line, under `$XDG_CACHE_HOME/linkleaf/subscriptions.txt`.
+fn subscriptions_path() -> Result<PathBuf> {
+    let cache_dir = std::env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| dirs_home().map(|h| h.join(".cache")))
+        .context("could not resolve a cache directory ($XDG_CACHE_HOME or $HOME)")?;
+    Ok(cache_dir.join("linkleaf").join("subscriptions.txt"))
+}
+
+fn dirs_home() -> Option<PathBuf> {
+    std::env::var_os("HOME").map(PathBuf::from)
+}
+
+/// Add `url` to the persisted subscription list, if it isn't already tracked.
+pub fn subscribe(url: &str) -> Result<()> {
+    let path = subscriptions_path()?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .with_context(|| format!("failed to create directory {}", dir.display()))?;
+    }
+    let existing = fs::read_to_string(&path).unwrap_or_default();
+    if existing.lines().any(|l| l == url) {
+        return Ok(());
+    }
+    let mut contents = existing;
+    if !contents.is_empty() && !contents.ends_with('\n') {
+        contents.push('\n');
+    }
+    contents.push_str(url);
+    contents.push('\n');
+    fs::write(&path, contents).with_context(|| format!("failed to write {}", path.display()))
+}
+
+/// Return every feed URL currently tracked for unattended `pull` runs.
+pub fn tracked_feeds() -> Result<Vec<String>> {
+    let path = subscriptions_path()?;
+    match fs::read_to_string(&path) {
+        Ok(s) => Ok(s.lines().map(|l| l.trim().to_string()).filter(|l| !l.is_empty()).collect()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(e).with_context(|| format!("failed to read {}", path.display())),
+    }
+}
+
+/// Result of merging one remote feed into the local feed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PullStats {
+    pub inserted: usize,
+    pub updated: usize,
+}
+
+/// Fetch `url`, parse it with `feed-rs` (RSS 2.0, Atom, or JSON Feed), and
+/// merge every entry into the feed at `file`, deduping by URL so re-pulling
+/// is idempotent. Delegates the actual fetch/parse/merge to
+/// [`crate::import::import_one`]; `pull` only adds the subscription-list
+/// bookkeeping on top.
+pub fn pull_one(file: &Path, url: &str) -> Result<PullStats> {
+    let stats = import_one(file, url)?;
+    Ok(PullStats {
+        inserted: stats.inserted,
+        updated: stats.updated,
+    })
+}
+
+/// Fetch and merge every URL in `urls` into `file`, summing the per-feed stats.
+pub fn pull(file: &Path, urls: &[String]) -> Result<PullStats> {
+    let mut total = PullStats::default();
+    for url in urls {
+        let stats = pull_one(file, url)?;
+        total.inserted += stats.inserted;
+        total.updated += stats.updated;
+    }
+    Ok(total)
+}
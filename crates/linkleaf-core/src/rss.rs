@@ -1,59 +1,80 @@
+use crate::TS_FMT;
 use crate::linkleaf_proto::{Feed, Link};
+use crate::sort_links_by_date_desc;
 use anyhow::Result;
-use rss::{CategoryBuilder, ChannelBuilder, GuidBuilder, Item, ItemBuilder};
-use time::format_description::{FormatItem, well_known::Rfc2822};
-use time::{OffsetDateTime, PrimitiveDateTime};
-
-const TS_FMT: &[FormatItem<'_>] =
-    time::macros::format_description!("[year]-[month]-[day] [hour]:[minute]:[second]");
-
-// fn parse_local(s: &str) -> Option<OffsetDateTime> {
-//     let naive = PrimitiveDateTime::parse(s, TS_FMT).ok()?;
-//     let local_off = OffsetDateTime::now_local().ok()?.offset();
-//     Some(naive.assume_offset(*local_off))
-// }
-
-// //#[cfg(feature = "rss")]
-// pub fn feed_to_rss_xml(feed: &Feed, site_title: &str, site_link: &str) -> Result<String> {
-//     let items: Vec<Item> = feed.links.iter().map(|l| link_to_rss_item(l)).collect();
-//     let description = format!("Feed about {} generated through Linkleaf", &feed.title);
-
-//     let channel = ChannelBuilder::default()
-//         .title(if feed.title.is_empty() {
-//             site_title.to_string()
-//         } else {
-//             feed.title.clone()
-//         })
-//         .link(site_link.to_string())
-//         .description(description) // if you have it; else set a default
-//         .items(items)
-//         .build();
-
-//     let mut buf = Vec::new();
-//     channel.pretty_write_to(&mut buf, b' ', 2)?;
-//     Ok(String::from_utf8(buf)?)
-// }
-
-// fn link_to_rss_item(l: &Link) -> rss::Item {
-//     let pub_date = parse_local(&l.date).and_then(|dt| dt.format(&Rfc2822).ok());
-
-//     let cats = l
-//         .tags
-//         .iter()
-//         .map(|t| CategoryBuilder::default().name(t.clone()).build())
-//         .collect::<Vec<_>>();
-
-//     ItemBuilder::default()
-//         .title(Some(l.title.clone()))
-//         .link(Some(l.url.clone()))
-//         .description((!l.summary.is_empty()).then(|| l.summary.clone()))
-//         .categories(cats)
-//         .guid(Some(
-//             GuidBuilder::default()
-//                 .value(format!("urn:uuid:{}", l.id))
-//                 .permalink(false)
-//                 .build(),
-//         ))
-//         .pub_date(pub_date)
-//         .build()
-// }
+use rss::{CategoryBuilder, ChannelBuilder, GuidBuilder, Item, ItemBuilder, SourceBuilder};
+use time::format_description::well_known::Rfc2822;
+use time::PrimitiveDateTime;
+
+fn parse_local(s: &str) -> Option<time::OffsetDateTime> {
+    let naive = PrimitiveDateTime::parse(s, TS_FMT).ok()?;
+    let local_off = time::OffsetDateTime::now_local().ok()?.offset();
+    Some(naive.assume_offset(local_off))
+}
+
+/// Serialize a [`Feed`] to an RSS 2.0 XML document.
+///
+/// `site_title`/`site_link` are used as channel-level fallbacks when
+/// `feed.title` is empty. Links are sorted newest-first via
+/// [`crate::sort_links_by_date_desc`] before `limit`, if given, caps the
+/// number of items to the first `limit` (the common "last N items"
+/// convention for web feeds).
+pub fn feed_to_rss_xml(
+    feed: &Feed,
+    site_title: &str,
+    site_link: &str,
+    limit: Option<usize>,
+) -> Result<String> {
+    let mut links = feed.links.clone();
+    sort_links_by_date_desc(&mut links);
+    let n = limit.unwrap_or(links.len()).min(links.len());
+    let items: Vec<Item> = links[..n].iter().map(link_to_rss_item).collect();
+    let description = format!("Feed about {} generated through Linkleaf", &feed.title);
+
+    let channel = ChannelBuilder::default()
+        .title(if feed.title.is_empty() {
+            site_title.to_string()
+        } else {
+            feed.title.clone()
+        })
+        .link(site_link.to_string())
+        .description(description)
+        .items(items)
+        .build();
+
+    let mut buf = Vec::new();
+    channel.pretty_write_to(&mut buf, b' ', 2)?;
+    Ok(String::from_utf8(buf)?)
+}
+
+fn link_to_rss_item(l: &Link) -> rss::Item {
+    let pub_date = parse_local(&l.date).and_then(|dt| dt.format(&Rfc2822).ok());
+
+    let cats = l
+        .tags
+        .iter()
+        .map(|t| CategoryBuilder::default().name(t.clone()).build())
+        .collect::<Vec<_>>();
+
+    let source = (!l.via.is_empty()).then(|| {
+        SourceBuilder::default()
+            .url(l.via.clone())
+            .title(Some(l.via.clone()))
+            .build()
+    });
+
+    ItemBuilder::default()
+        .title(Some(l.title.clone()))
+        .link(Some(l.url.clone()))
+        .description((!l.summary.is_empty()).then(|| l.summary.clone()))
+        .categories(cats)
+        .guid(Some(
+            GuidBuilder::default()
+                .value(format!("urn:uuid:{}", l.id))
+                .permalink(false)
+                .build(),
+        ))
+        .source(source)
+        .pub_date(pub_date)
+        .build()
+}
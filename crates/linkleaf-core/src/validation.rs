@@ -0,0 +1,159 @@
+use anyhow::Result;
+use time::{Date, Duration, Month, OffsetDateTime, macros::format_description};
+
+/// Parse a date as strict `YYYY-MM-DD`, or, failing that, as a relative or
+/// natural-language date: `today`, `yesterday`, `tomorrow`, or a signed
+/// offset like `-3d`, `+2w`, `-1m` (days/weeks/months) against local "today".
+pub fn parse_date(s: &str) -> Result<Date, String> {
+    let trimmed = s.trim();
+    let fmt = format_description!("[year]-[month]-[day]");
+    if let Ok(d) = Date::parse(trimmed, &fmt) {
+        return Ok(d);
+    }
+    parse_date_relative(trimmed)
+}
+
+fn today_local() -> Result<Date, String> {
+    OffsetDateTime::now_local()
+        .map(|o| o.date())
+        .map_err(|e| format!("failed to get local time offset: {e}"))
+}
+
+fn offset_days(base: Date, days: i64) -> Result<Date, String> {
+    let shifted = if days >= 0 {
+        base.checked_add(Duration::days(days))
+    } else {
+        base.checked_sub(Duration::days(-days))
+    };
+    shifted.ok_or_else(|| "date offset out of range".to_string())
+}
+
+/// Shift `base` by `months`, clamping the day-of-month when the target month
+/// is shorter (e.g. Mar 31 − 1m → Feb 28, not an error).
+fn offset_months(base: Date, months: i64) -> Result<Date, String> {
+    let base_month0 = i64::from(u8::from(base.month())) - 1;
+    let total_months = i64::from(base.year()) * 12 + base_month0 + months;
+    let year = total_months.div_euclid(12) as i32;
+    let month0 = total_months.rem_euclid(12) as u8;
+    let month = Month::try_from(month0 + 1).map_err(|e| e.to_string())?;
+    let last_day = time::util::days_in_year_month(year, month);
+    let day = base.day().min(last_day);
+    Date::from_calendar_date(year, month, day).map_err(|e| e.to_string())
+}
+
+/// Resolve a relative/natural-language date (everything `parse_date` doesn't
+/// recognize as strict ISO) against local "today".
+fn parse_date_relative(s: &str) -> Result<Date, String> {
+    let lower = s.to_lowercase();
+    let today = today_local()?;
+
+    match lower.as_str() {
+        "today" => return Ok(today),
+        "yesterday" => return offset_days(today, -1),
+        "tomorrow" => return offset_days(today, 1),
+        _ => {}
+    }
+
+    let sign = match lower.as_bytes().first() {
+        Some(b'+') => 1,
+        Some(b'-') => -1,
+        _ => return Err(format!("invalid date: {s}")),
+    };
+    let body = &lower[1..];
+    let unit = body.chars().last().ok_or_else(|| format!("invalid date: {s}"))?;
+    let digits = &body[..body.len() - unit.len_utf8()];
+    let amount: i64 = digits
+        .parse()
+        .map_err(|_| format!("invalid date: {s}"))?;
+
+    match unit {
+        'd' => offset_days(today, sign * amount),
+        'w' => offset_days(today, sign * amount * 7),
+        'm' => offset_months(today, sign * amount),
+        _ => Err(format!("invalid date: {s}")),
+    }
+}
+
+pub fn parse_tags(raw: &str) -> Result<Vec<String>, String> {
+    let tags = raw
+        .split(',')
+        .map(|t| t.trim())
+        .filter(|t| !t.is_empty())
+        .map(|t| t.to_string())
+        .collect();
+
+    Ok(tags)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::Date;
+
+    #[test]
+    fn parse_date_accepts_strict_iso() {
+        let d = parse_date("2025-09-02").expect("valid date");
+        assert_eq!(
+            d,
+            Date::from_calendar_date(2025, time::Month::September, 2).unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_date_rejects_wrong_format() {
+        assert!(parse_date("2025/09/02").is_err());
+        assert!(parse_date("2025-9-2").is_err());
+    }
+
+    #[test]
+    fn parse_tags_trims_and_skips_empties() {
+        let tags = parse_tags(" a, b ,  ,c , , ").expect("ok");
+        assert_eq!(tags, vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn parse_date_resolves_keywords_against_today() {
+        let today = today_local().unwrap();
+        assert_eq!(parse_date("today").unwrap(), today);
+        assert_eq!(parse_date("Today").unwrap(), today);
+        assert_eq!(
+            parse_date("yesterday").unwrap(),
+            offset_days(today, -1).unwrap()
+        );
+        assert_eq!(
+            parse_date("tomorrow").unwrap(),
+            offset_days(today, 1).unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_date_resolves_signed_day_and_week_offsets() {
+        let today = today_local().unwrap();
+        assert_eq!(
+            parse_date("-3d").unwrap(),
+            offset_days(today, -3).unwrap()
+        );
+        assert_eq!(
+            parse_date("+2w").unwrap(),
+            offset_days(today, 14).unwrap()
+        );
+    }
+
+    #[test]
+    fn offset_months_clamps_day_of_month_on_overflow() {
+        let jan31 = Date::from_calendar_date(2025, time::Month::January, 31).unwrap();
+        let dec31 = Date::from_calendar_date(2024, time::Month::December, 31).unwrap();
+        assert_eq!(offset_months(jan31, -1).unwrap(), dec31);
+
+        let mar31 = Date::from_calendar_date(2025, time::Month::March, 31).unwrap();
+        let feb28 = Date::from_calendar_date(2025, time::Month::February, 28).unwrap();
+        assert_eq!(offset_months(mar31, -1).unwrap(), feb28);
+    }
+
+    #[test]
+    fn parse_date_rejects_nonsense_offsets() {
+        assert!(parse_date("3d").is_err()); // missing sign
+        assert!(parse_date("-3y").is_err()); // unsupported unit
+        assert!(parse_date("next tuesday").is_err());
+    }
+}
@@ -0,0 +1,81 @@
+//! XDG-aware default feed location and named multi-feed support.
+//!
+//! linkleaf's data lives under `$XDG_DATA_HOME/linkleaf` (falling back to
+//! `$HOME/.local/share/linkleaf`); each named feed is one `<name>.pb` file
+//! under a `feeds/` subdirectory there, so a user can keep e.g. a `work` and
+//! a `reading` feed side by side without juggling paths.
+
+use anyhow::{Context, Result};
+use std::path::PathBuf;
+
+fn home_dir() -> Option<PathBuf> {
+    std::env::var_os("HOME").map(PathBuf::from)
+}
+
+/// `$XDG_DATA_HOME/linkleaf`, falling back to `$HOME/.local/share/linkleaf`.
+pub fn data_dir() -> Result<PathBuf> {
+    let data_home = std::env::var_os("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .or_else(|| home_dir().map(|h| h.join(".local/share")))
+        .context("could not resolve a data directory ($XDG_DATA_HOME or $HOME)")?;
+    Ok(data_home.join("linkleaf"))
+}
+
+/// The directory named feeds live under.
+pub fn feeds_dir() -> Result<PathBuf> {
+    Ok(data_dir()?.join("feeds"))
+}
+
+/// Resolve a feed `name` (no extension, no path separators) to its `.pb`
+/// path under [`feeds_dir`].
+pub fn feed_path(name: &str) -> Result<PathBuf> {
+    Ok(feeds_dir()?.join(format!("{name}.pb")))
+}
+
+/// List the names (file stems) of every feed currently under [`feeds_dir`],
+/// sorted alphabetically. Returns an empty list if the directory doesn't
+/// exist yet.
+pub fn list_feeds() -> Result<Vec<String>> {
+    let dir = feeds_dir()?;
+    let entries = match std::fs::read_dir(&dir) {
+        Ok(e) => e,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e).with_context(|| format!("failed to read {}", dir.display())),
+    };
+    let mut names: Vec<String> = entries
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().extension().is_some_and(|ext| ext == "pb"))
+        .filter_map(|e| e.path().file_stem().map(|s| s.to_string_lossy().into_owned()))
+        .collect();
+    names.sort();
+    Ok(names)
+}
+
+/// Whether `s` looks like an explicit path rather than a bare feed name.
+fn looks_like_path(s: &str) -> bool {
+    s.contains(std::path::MAIN_SEPARATOR) || s.ends_with(".pb")
+}
+
+/// Resolve CLI input that may be an explicit path (contains a path
+/// separator, or ends in `.pb`) or a bare named feed, to a concrete `.pb`
+/// path. Falls back to the `default` named feed when `input` is `None`.
+pub fn resolve_feed(input: Option<&str>) -> Result<PathBuf> {
+    match input {
+        None => feed_path("default"),
+        Some(s) if looks_like_path(s) => Ok(PathBuf::from(s)),
+        Some(name) => feed_path(name),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn looks_like_path_detects_separators_and_pb_extension() {
+        assert!(looks_like_path("feed/mylinks.pb"));
+        assert!(looks_like_path("mylinks.pb"));
+        assert!(!looks_like_path("work"));
+        assert!(!looks_like_path("reading"));
+    }
+}